@@ -0,0 +1,32 @@
+//! Throughput of English conversion over a large batch; run with
+//! `cargo bench --features bench`
+//!
+//! The `convert` benchmarks compare spelling every group from the sub-1000 cache with
+//! spelling it afresh, the path builds without `std` take.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use number_to_text::{convert_for_bench, number_to_text};
+
+fn convert_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert");
+    group.sample_size(10);
+    group.bench_function("number_to_text 0..=1_000_000", |b| {
+        b.iter(|| {
+            for number in 0..=1_000_000 {
+                black_box(number_to_text(black_box(number)).unwrap());
+            }
+        })
+    });
+    for (name, cached) in [("cached", true), ("uncached", false)] {
+        group.bench_function(format!("convert {} 0..=1_000_000", name), |b| {
+            b.iter(|| {
+                for number in 0..=1_000_000 {
+                    black_box(convert_for_bench(black_box(number), cached).unwrap());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, convert_batch);
+criterion_main!(benches);
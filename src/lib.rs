@@ -0,0 +1,5958 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Error types for number conversion
+#[derive(Debug)]
+pub enum NumberConversionError {
+    /// Input number is too large to convert (exceeds i64::MAX/2)
+    ValueTooLarge(i64),
+    /// Invalid input provided during conversion
+    InvalidInput(String),
+    /// Internal conversion error, wrapping the failure that caused it
+    ConversionError {
+        /// What was being converted when the failure happened
+        context: String,
+        /// The underlying error
+        source: Box<NumberConversionError>,
+    },
+    /// Error parsing decimal number
+    DecimalError(String),
+    /// Unsupported language
+    UnsupportedLanguage(String),
+}
+
+impl fmt::Display for NumberConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumberConversionError::ValueTooLarge(val) => {
+                write!(f, "Number {} is too large to convert", val)
+            }
+            NumberConversionError::InvalidInput(msg) => {
+                write!(f, "Invalid input: {}", msg)
+            }
+            NumberConversionError::ConversionError { context, .. } => {
+                write!(f, "Conversion error: {}", context)
+            }
+            NumberConversionError::DecimalError(msg) => {
+                write!(f, "Decimal error: {}", msg)
+            }
+            NumberConversionError::UnsupportedLanguage(lang) => {
+                write!(f, "Unsupported language: {}", lang)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NumberConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NumberConversionError::ConversionError { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Represents numeric scale units used in number conversion
+const SCALE_UNITS: [(i64, &str); 6] = [
+    (1_000_000_000_000_000_000, "Quintillion"),
+    (1_000_000_000_000_000, "Quadrillion"),
+    (1_000_000_000_000, "Trillion"),
+    (1_000_000_000, "Billion"),
+    (1_000_000, "Million"),
+    (1_000, "Thousand"),
+];
+
+/// Scale units of the traditional European long scale, where a billion is 10^12
+const LONG_SCALE_UNITS: [(i64, &str); 6] = [
+    (1_000_000_000_000_000_000, "Trillion"),
+    (1_000_000_000_000_000, "Billiard"),
+    (1_000_000_000_000, "Billion"),
+    (1_000_000_000, "Milliard"),
+    (1_000_000, "Million"),
+    (1_000, "Thousand"),
+];
+
+/// Scale units of the Indian system, grouping digits 2-2-3 below a crore
+const INDIAN_SCALE_UNITS: [(i64, &str); 3] = [
+    (10_000_000, "Crore"),
+    (100_000, "Lakh"),
+    (1_000, "Thousand"),
+];
+
+/// Module containing core number conversion functionality
+mod converter {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::io::{self, Write};
+    #[cfg(feature = "std")]
+    use std::sync::OnceLock;
+
+    const ROMAN_NUMERALS: [(i64, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    const MAX_ROMAN_LEN: usize = 15;
+
+    /// Convert a number to Roman numerals
+    pub fn to_roman(number: i64) -> Result<String, NumberConversionError> {
+        if number <= 0 {
+            return Err(NumberConversionError::InvalidInput(
+                "Roman numerals must be positive".to_string(),
+            ));
+        }
+        if number > 3999 {
+            return Err(NumberConversionError::InvalidInput(
+                "Roman numerals cannot exceed 3999".to_string(),
+            ));
+        }
+
+        let mut result = String::new();
+        let mut remaining = number;
+
+        for &(value, numeral) in ROMAN_NUMERALS.iter() {
+            while remaining >= value {
+                result.push_str(numeral);
+                remaining -= value;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Convert a number to lowercase Roman numerals ("iv", "mcmxciv"), as used for
+    /// outline and sub-clause numbering
+    pub fn to_roman_lower(number: i64) -> Result<String, NumberConversionError> {
+        Ok(to_roman(number)?.to_lowercase())
+    }
+
+    /// Convert a clock hour to Roman numerals as written on clock faces, with the
+    /// additive "IIII" for four and subtractive notation elsewhere ("IX")
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for hours outside 1-12.
+    pub fn to_roman_clock(hour: i64) -> Result<String, NumberConversionError> {
+        match hour {
+            4 => Ok("IIII".to_string()),
+            1..=12 => to_roman(hour),
+            _ => Err(NumberConversionError::InvalidInput(format!(
+                "Clock hours run from 1 to 12, got {}",
+                hour
+            ))),
+        }
+    }
+
+    /// Parse a Roman numeral (case-insensitive) back to its integer value
+    ///
+    /// Only canonical subtractive numerals in the range 1-3999 are accepted, so
+    /// "IIII" or "VX" are rejected.
+    pub fn from_roman(numeral: &str) -> Result<i64, NumberConversionError> {
+        let numeral = numeral.trim();
+        // "MMMDCCCLXXXVIII" (3888) is the longest canonical numeral
+        if numeral.len() > MAX_ROMAN_LEN {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Roman numeral is longer than {} characters",
+                MAX_ROMAN_LEN
+            )));
+        }
+
+        let numeral = numeral.to_uppercase();
+        let mut total = 0;
+        let mut remaining = numeral.as_str();
+
+        for &(value, symbol) in ROMAN_NUMERALS.iter() {
+            while let Some(rest) = remaining.strip_prefix(symbol) {
+                total += value;
+                if total > 3999 {
+                    return Err(NumberConversionError::ValueTooLarge(total));
+                }
+                remaining = rest;
+            }
+        }
+
+        if !remaining.is_empty() || to_roman(total).ok().as_deref() != Some(numeral.as_str()) {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Invalid Roman numeral: {}",
+                numeral
+            )));
+        }
+
+        Ok(total)
+    }
+
+    /// Capitalization applied to spelled words
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum Casing {
+        /// Capitalize every number word ("One Hundred and Five Thousand")
+        #[default]
+        Title,
+        /// Capitalize only scale words ("one Hundred and five Thousand")
+        ScaleWords,
+        /// Lowercase every word ("one hundred and five thousand")
+        Lower,
+        /// Capitalize the first word and the scale words from Thousand up, as in a
+        /// sentence ("One hundred and five Thousand")
+        SentenceScales,
+    }
+
+    /// Where "and" is inserted in English numbers
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum AndStyle {
+        /// Only after "Hundred" ("One Hundred and Five", "One Thousand Five")
+        #[default]
+        Hundreds,
+        /// After "Hundred" and before a final group below one hundred
+        /// ("One Thousand and Five")
+        British,
+        /// Never ("One Hundred Five")
+        Omit,
+    }
+
+    impl FromStr for AndStyle {
+        type Err = NumberConversionError;
+
+        fn from_str(style: &str) -> Result<Self, Self::Err> {
+            match style.to_lowercase().as_str() {
+                "hundreds" => Ok(AndStyle::Hundreds),
+                "british" => Ok(AndStyle::British),
+                "omit" | "none" => Ok(AndStyle::Omit),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown and style: {}",
+                    style
+                ))),
+            }
+        }
+    }
+
+    /// Naming of the scales above a million in English
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum ScaleSystem {
+        /// A new name every thousandfold: Billion is 10^9, Trillion 10^12
+        #[default]
+        Short,
+        /// A new name every millionfold, with "-iard" names in between: Milliard is
+        /// 10^9, Billion 10^12 and Billiard 10^15
+        Long,
+        /// Lakh (10^5) and crore (10^7); counts of a thousand crore or more are
+        /// themselves grouped ("One Lakh Crore" for 10^12)
+        Indian,
+    }
+
+    impl ScaleSystem {
+        /// The scale words of this system with their divisors, largest first
+        pub fn table(self) -> &'static [(i64, &'static str)] {
+            match self {
+                ScaleSystem::Short => &SCALE_UNITS,
+                ScaleSystem::Long => &LONG_SCALE_UNITS,
+                ScaleSystem::Indian => &INDIAN_SCALE_UNITS,
+            }
+        }
+    }
+
+    impl FromStr for ScaleSystem {
+        type Err = NumberConversionError;
+
+        fn from_str(system: &str) -> Result<Self, Self::Err> {
+            match system.to_lowercase().as_str() {
+                "short" => Ok(ScaleSystem::Short),
+                "long" => Ok(ScaleSystem::Long),
+                "indian" => Ok(ScaleSystem::Indian),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown scale system: {}",
+                    system
+                ))),
+            }
+        }
+    }
+
+    /// How the lakh and crore labels of the Indian scale system are written
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum IndianLabels {
+        /// "Lakh" and "Crore" whatever the count ("Twelve Lakh")
+        #[default]
+        Words,
+        /// "Lakhs" and "Crores" for counts other than one ("Twelve Lakhs")
+        Plural,
+        /// "L" and "Cr" ("Twelve L")
+        Abbreviated,
+    }
+
+    impl FromStr for IndianLabels {
+        type Err = NumberConversionError;
+
+        fn from_str(labels: &str) -> Result<Self, Self::Err> {
+            match labels.to_lowercase().as_str() {
+                "words" => Ok(IndianLabels::Words),
+                "plural" => Ok(IndianLabels::Plural),
+                "abbreviated" | "short" => Ok(IndianLabels::Abbreviated),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown Indian labels: {}",
+                    labels
+                ))),
+            }
+        }
+    }
+
+    /// How English output is prepared for display inside right-to-left (e.g. Arabic) text
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum BidiMode {
+        /// Plain left-to-right text
+        #[default]
+        Plain,
+        /// Wrapped in the Unicode isolate marks U+2066 (LRI) and U+2069 (PDI), so the
+        /// words keep their order inside an RTL paragraph
+        Isolate,
+        /// Words in reverse order ("Five and Hundred One"), for renderers that lay
+        /// tokens out right to left without bidi support
+        Reverse,
+    }
+
+    impl BidiMode {
+        fn apply(self, text: String) -> String {
+            match self {
+                BidiMode::Plain => text,
+                BidiMode::Isolate => format!("\u{2066}{}\u{2069}", text),
+                BidiMode::Reverse => text.split(' ').rev().collect::<Vec<_>>().join(" "),
+            }
+        }
+    }
+
+    impl FromStr for BidiMode {
+        type Err = NumberConversionError;
+
+        fn from_str(mode: &str) -> Result<Self, Self::Err> {
+            match mode.to_lowercase().as_str() {
+                "plain" => Ok(BidiMode::Plain),
+                "isolate" => Ok(BidiMode::Isolate),
+                "reverse" => Ok(BidiMode::Reverse),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown bidi mode: {}",
+                    mode
+                ))),
+            }
+        }
+    }
+
+    /// Word introducing negative numbers in English
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum NegativeWord {
+        /// "Minus Forty Two"
+        #[default]
+        Minus,
+        /// "Negative Forty Two"
+        Negative,
+        /// "Dash Forty Two", reading the "-" sign itself as in data entry
+        Dash,
+    }
+
+    impl NegativeWord {
+        fn as_str(self) -> &'static str {
+            match self {
+                NegativeWord::Minus => "Minus",
+                NegativeWord::Negative => "Negative",
+                NegativeWord::Dash => "Dash",
+            }
+        }
+    }
+
+    impl FromStr for NegativeWord {
+        type Err = NumberConversionError;
+
+        fn from_str(word: &str) -> Result<Self, Self::Err> {
+            match word.to_lowercase().as_str() {
+                "minus" => Ok(NegativeWord::Minus),
+                "negative" => Ok(NegativeWord::Negative),
+                "dash" => Ok(NegativeWord::Dash),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown negative word: {}",
+                    word
+                ))),
+            }
+        }
+    }
+
+    /// Where the negative word is placed relative to the number
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum SignPlacement {
+        /// "Minus Forty Two"
+        #[default]
+        Prefix,
+        /// "Forty Two Minus"
+        Suffix,
+        /// "(Forty Two)", the accounting style; the negative word is not used
+        Parentheses,
+    }
+
+    impl FromStr for SignPlacement {
+        type Err = NumberConversionError;
+
+        fn from_str(placement: &str) -> Result<Self, Self::Err> {
+            match placement.to_lowercase().as_str() {
+                "prefix" => Ok(SignPlacement::Prefix),
+                "suffix" => Ok(SignPlacement::Suffix),
+                "parentheses" | "parens" => Ok(SignPlacement::Parentheses),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown sign placement: {}",
+                    placement
+                ))),
+            }
+        }
+    }
+
+    /// Alternative words for a standalone zero
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ZeroWord {
+        /// "Zero"
+        Zero,
+        /// "Nought" (British)
+        Nought,
+        /// "Nil" (sports scores)
+        Nil,
+        /// "Oh" (years, phone numbers)
+        Oh,
+    }
+
+    impl ZeroWord {
+        fn as_str(self) -> &'static str {
+            match self {
+                ZeroWord::Zero => "Zero",
+                ZeroWord::Nought => "Nought",
+                ZeroWord::Nil => "Nil",
+                ZeroWord::Oh => "Oh",
+            }
+        }
+    }
+
+    impl FromStr for ZeroWord {
+        type Err = NumberConversionError;
+
+        fn from_str(word: &str) -> Result<Self, Self::Err> {
+            match word.to_lowercase().as_str() {
+                "zero" => Ok(ZeroWord::Zero),
+                "nought" => Ok(ZeroWord::Nought),
+                "nil" => Ok(ZeroWord::Nil),
+                "oh" => Ok(ZeroWord::Oh),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown zero word: {}",
+                    word
+                ))),
+            }
+        }
+    }
+
+    /// Options controlling how numbers are spelled
+    #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    pub struct ConversionOptions {
+        /// Capitalization of the output words
+        pub casing: Casing,
+        /// Word marking negative numbers
+        pub negative_word: NegativeWord,
+        /// Whether the negative word comes before or after the number
+        pub sign_placement: SignPlacement,
+        /// Word used for a standalone zero; `None` uses the language's own zero word
+        pub zero_word: Option<ZeroWord>,
+        /// Placement of the "and" conjunction
+        pub and_style: AndStyle,
+        /// Join compound tens with a hyphen ("Twenty-One")
+        pub hyphenate: bool,
+        /// Read exactly one hundred, thousand, million, ... as "A Hundred", "A Thousand"
+        pub indefinite_article: bool,
+        /// Short or long scale names above a million
+        pub scale_system: ScaleSystem,
+        /// Spelling of "Lakh" and "Crore" when `scale_system` is Indian
+        pub indian_labels: IndianLabels,
+        /// Preparation of the joined words for right-to-left contexts
+        pub bidi: BidiMode,
+    }
+
+    /// A number that displays as its words
+    ///
+    /// ```
+    /// # use number_to_text::SpelledNumber;
+    /// assert_eq!(SpelledNumber::new(42)?.to_string(), "Forty Two");
+    /// assert_eq!("-7".parse::<SpelledNumber>()?.to_string(), "Minus Seven");
+    /// # Ok::<(), number_to_text::NumberConversionError>(())
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct SpelledNumber {
+        value: i64,
+        language: String,
+        options: ConversionOptions,
+    }
+
+    impl SpelledNumber {
+        /// Wraps a number spelled in English with the default options
+        ///
+        /// # Errors
+        /// Returns `NumberConversionError::ValueTooLarge` if the number is too large to
+        /// spell.
+        pub fn new(value: i64) -> Result<Self, NumberConversionError> {
+            Self::with_options(value, ConversionOptions::default())
+        }
+
+        /// Wraps a number spelled in English with the given options
+        pub fn with_options(
+            value: i64,
+            options: ConversionOptions,
+        ) -> Result<Self, NumberConversionError> {
+            let number = SpelledNumber {
+                value,
+                language: "en".to_string(),
+                options,
+            };
+            number.spell()?;
+            Ok(number)
+        }
+
+        /// Wraps a number spelled in the given language
+        ///
+        /// Conversion options only apply to English.
+        ///
+        /// # Errors
+        /// Returns `NumberConversionError::UnsupportedLanguage` for unknown languages.
+        pub fn with_language(value: i64, lang: &str) -> Result<Self, NumberConversionError> {
+            let number = SpelledNumber {
+                value,
+                language: lang.to_lowercase(),
+                options: ConversionOptions::default(),
+            };
+            number.spell()?;
+            Ok(number)
+        }
+
+        /// The wrapped number
+        pub fn value(&self) -> i64 {
+            self.value
+        }
+
+        /// The language code the number is spelled in
+        pub fn language(&self) -> &str {
+            &self.language
+        }
+
+        fn spell(&self) -> Result<String, NumberConversionError> {
+            if get_language_words(&self.language)?.language == Language::English {
+                number_to_text_with_options(self.value, &self.options)
+            } else {
+                number_to_text_lang(self.value, &self.language)
+            }
+        }
+    }
+
+    impl fmt::Display for SpelledNumber {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            // Constructors already spelled the value once, so this cannot fail
+            f.write_str(&self.spell().map_err(|_| fmt::Error)?)
+        }
+    }
+
+    impl FromStr for SpelledNumber {
+        type Err = NumberConversionError;
+
+        fn from_str(input: &str) -> Result<Self, Self::Err> {
+            let value = input.trim().parse::<i64>().map_err(|_| {
+                NumberConversionError::InvalidInput(format!("Not an integer: {}", input))
+            })?;
+            SpelledNumber::new(value)
+        }
+    }
+
+    /// A reusable converter for one language and set of options
+    ///
+    /// The language table is looked up once when the converter is built and kept as a
+    /// `&'static` reference, so conversions take `&self` and need no locking: share one
+    /// converter between threads with an `Arc`, or clone it cheaply.
+    ///
+    /// ```
+    /// # use number_to_text::Converter;
+    /// let converter = Converter::new("en")?;
+    /// assert_eq!(converter.convert(42)?, "Forty Two");
+    /// # Ok::<(), number_to_text::NumberConversionError>(())
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct Converter {
+        words: &'static LanguageWords,
+        options: ConversionOptions,
+    }
+
+    impl Converter {
+        /// Builds a converter for the given language with the default options
+        ///
+        /// # Errors
+        /// Returns `NumberConversionError::UnsupportedLanguage` for unknown languages.
+        pub fn new(lang: &str) -> Result<Self, NumberConversionError> {
+            Ok(Converter {
+                words: get_language_words(lang)?,
+                options: ConversionOptions::default(),
+            })
+        }
+
+        /// Replaces the conversion options, which only apply to English
+        pub fn with_options(mut self, options: ConversionOptions) -> Self {
+            self.options = options;
+            self
+        }
+
+        /// The options in use
+        pub fn options(&self) -> &ConversionOptions {
+            &self.options
+        }
+
+        /// Spells a number with the cached language and options
+        pub fn convert(&self, number: i64) -> Result<String, NumberConversionError> {
+            if self.words.language == Language::English {
+                number_to_text_with_options(number, &self.options)
+            } else {
+                Ok(lang_segments(number, self.words)?.join(" "))
+            }
+        }
+    }
+
+    /// Named bundles of English spelling choices
+    ///
+    /// Both presets use the short scale and lowercase words; override individual fields
+    /// with struct update syntax, e.g.
+    /// `ConversionOptions { casing: Casing::Title, ..Dialect::BritishEnglish.options() }`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Dialect {
+        /// "one hundred five", "twenty-one", "zero"
+        AmericanEnglish,
+        /// "one hundred and five", "one thousand and five", "twenty-one", "nought"
+        BritishEnglish,
+    }
+
+    impl Dialect {
+        /// Returns the conversion options making up this preset
+        pub fn options(self) -> ConversionOptions {
+            let (and_style, zero_word) = match self {
+                Dialect::AmericanEnglish => (AndStyle::Omit, ZeroWord::Zero),
+                Dialect::BritishEnglish => (AndStyle::British, ZeroWord::Nought),
+            };
+            ConversionOptions {
+                casing: Casing::Lower,
+                zero_word: Some(zero_word),
+                and_style,
+                hyphenate: true,
+                ..Default::default()
+            }
+        }
+    }
+
+    impl FromStr for Dialect {
+        type Err = NumberConversionError;
+
+        fn from_str(dialect: &str) -> Result<Self, Self::Err> {
+            match dialect.to_lowercase().as_str() {
+                "american" | "en-us" => Ok(Dialect::AmericanEnglish),
+                "british" | "en-gb" => Ok(Dialect::BritishEnglish),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown dialect: {}",
+                    dialect
+                ))),
+            }
+        }
+    }
+
+    /// Where a language puts its "and" inside a number
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum AndPlacement {
+        /// Between the hundreds and the rest of a group ("One Hundred and Five")
+        AfterHundreds,
+        /// Between the tens and the units ("Treinta y Cuatro")
+        TensUnits,
+        /// Between every group, tens and units included ("مائة و خمسة")
+        EveryGroup,
+    }
+
+    /// A scale word of a language and the forms its count selects
+    #[derive(Debug)]
+    struct ScaleWord {
+        value: i64,
+        singular: &'static str,
+        /// Follows a count above one, or only 3-10 in Arabic (see `scale_form`)
+        plural: &'static str,
+        /// Used alone for a count of two ("ألفان")
+        dual: Option<&'static str>,
+    }
+
+    /// Language-specific number words
+    #[derive(Debug)]
+    struct LanguageWords {
+        /// The language the table spells, which picks its grammar rules
+        language: Language,
+        units: &'static [&'static str],
+        tens: &'static [&'static str],
+        /// The words for one to nine hundred, indexed by the hundreds digit
+        hundreds: &'static [&'static str],
+        /// The scale words from a million up, largest first
+        scales: &'static [ScaleWord],
+        thousand: ScaleWord,
+        /// The word for an exact hundred, alone in Spanish ("Cien")
+        hundred: &'static str,
+        zero: &'static str,
+        minus: &'static str,
+        /// Joins the parts of a number at `and_placement`; in English `AndStyle` can
+        /// also move or drop it
+        and: &'static str,
+        and_placement: AndPlacement,
+        point: &'static str,
+        /// Joins the last two items of a list ("One, Two and Three")
+        conjunction: &'static str,
+        /// Closes an amount written on a check ("One Hundred Dollars Only")
+        #[cfg(feature = "std")]
+        only: &'static str,
+        /// Words following a whole number for one, two and three quarters ("and a Half")
+        quarters: [&'static str; 3],
+        thousands_separator: char,
+    }
+
+    /// English language number words
+    const EN_WORDS: LanguageWords = LanguageWords {
+        language: Language::English,
+        units: &[
+            "",          // 0
+            "One",       // 1
+            "Two",       // 2
+            "Three",     // 3
+            "Four",      // 4
+            "Five",      // 5
+            "Six",       // 6
+            "Seven",     // 7
+            "Eight",     // 8
+            "Nine",      // 9
+            "Ten",       // 10
+            "Eleven",    // 11
+            "Twelve",    // 12
+            "Thirteen",  // 13
+            "Fourteen",  // 14
+            "Fifteen",   // 15
+            "Sixteen",   // 16
+            "Seventeen", // 17
+            "Eighteen",  // 18
+            "Nineteen",  // 19
+        ],
+        tens: &[
+            "",        // 0
+            "",        // 10 (handled in units)
+            "Twenty",  // 20
+            "Thirty",  // 30
+            "Forty",   // 40
+            "Fifty",   // 50
+            "Sixty",   // 60
+            "Seventy", // 70
+            "Eighty",  // 80
+            "Ninety",  // 90
+        ],
+        hundreds: &[
+            "",              // 0
+            "One Hundred",   // 100
+            "Two Hundred",   // 200
+            "Three Hundred", // 300
+            "Four Hundred",  // 400
+            "Five Hundred",  // 500
+            "Six Hundred",   // 600
+            "Seven Hundred", // 700
+            "Eight Hundred", // 800
+            "Nine Hundred",  // 900
+        ],
+        scales: &[
+            ScaleWord {
+                value: 1_000_000_000_000_000_000,
+                singular: "Quintillion",
+                plural: "Quintillion",
+                dual: None,
+            },
+            ScaleWord {
+                value: 1_000_000_000_000_000,
+                singular: "Quadrillion",
+                plural: "Quadrillion",
+                dual: None,
+            },
+            ScaleWord {
+                value: 1_000_000_000_000,
+                singular: "Trillion",
+                plural: "Trillion",
+                dual: None,
+            },
+            ScaleWord {
+                value: 1_000_000_000,
+                singular: "Billion",
+                plural: "Billion",
+                dual: None,
+            },
+            ScaleWord {
+                value: 1_000_000,
+                singular: "Million",
+                plural: "Million",
+                dual: None,
+            },
+        ],
+        thousand: ScaleWord {
+            value: 1_000,
+            singular: "Thousand",
+            plural: "Thousand",
+            dual: None,
+        },
+        hundred: "Hundred",
+        zero: "Zero",
+        minus: "Minus",
+        and: "and",
+        and_placement: AndPlacement::AfterHundreds,
+        point: "point",
+        conjunction: "and",
+        #[cfg(feature = "std")]
+        only: "Only",
+        quarters: ["and a Quarter", "and a Half", "and Three Quarters"],
+        thousands_separator: ',',
+    };
+
+    /// Spanish language number words
+    #[cfg(feature = "lang-es")]
+    const ES_WORDS: LanguageWords = LanguageWords {
+        language: Language::Spanish,
+        units: &[
+            "",           // 0
+            "Uno",        // 1
+            "Dos",        // 2
+            "Tres",       // 3
+            "Cuatro",     // 4
+            "Cinco",      // 5
+            "Seis",       // 6
+            "Siete",      // 7
+            "Ocho",       // 8
+            "Nueve",      // 9
+            "Diez",       // 10
+            "Once",       // 11
+            "Doce",       // 12
+            "Trece",      // 13
+            "Catorce",    // 14
+            "Quince",     // 15
+            "Dieciséis",  // 16
+            "Diecisiete", // 17
+            "Dieciocho",  // 18
+            "Diecinueve", // 19
+        ],
+        tens: &[
+            "",          // 0
+            "",          // 10 (handled in units)
+            "Veinte",    // 20
+            "Treinta",   // 30
+            "Cuarenta",  // 40
+            "Cincuenta", // 50
+            "Sesenta",   // 60
+            "Setenta",   // 70
+            "Ochenta",   // 80
+            "Noventa",   // 90
+        ],
+        // Quinientos, setecientos and novecientos are irregular
+        hundreds: &[
+            "",              // 0
+            "Ciento",        // 100 ("Cien" when nothing follows)
+            "Doscientos",    // 200
+            "Trescientos",   // 300
+            "Cuatrocientos", // 400
+            "Quinientos",    // 500
+            "Seiscientos",   // 600
+            "Setecientos",   // 700
+            "Ochocientos",   // 800
+            "Novecientos",   // 900
+        ],
+        // The long scale: a thousand million is "Mil Millones" and a billón is 10^12
+        scales: &[
+            ScaleWord {
+                value: 1_000_000_000_000_000_000,
+                singular: "Trillón",
+                plural: "Trillones",
+                dual: None,
+            },
+            ScaleWord {
+                value: 1_000_000_000_000,
+                singular: "Billón",
+                plural: "Billones",
+                dual: None,
+            },
+            ScaleWord {
+                value: 1_000_000,
+                singular: "Millón",
+                plural: "Millones",
+                dual: None,
+            },
+        ],
+        thousand: ScaleWord {
+            value: 1_000,
+            singular: "Mil",
+            plural: "Mil",
+            dual: None,
+        },
+        hundred: "Cien",
+        zero: "Cero",
+        minus: "Menos",
+        and: "y",
+        and_placement: AndPlacement::TensUnits,
+        point: "coma",
+        conjunction: "y",
+        #[cfg(feature = "std")]
+        only: "Solamente",
+        quarters: ["y Cuarto", "y Medio", "y Tres Cuartos"],
+        thousands_separator: '.',
+    };
+
+    /// Arabic language number words (masculine form)
+    #[cfg(feature = "lang-ar")]
+    const AR_WORDS: LanguageWords = LanguageWords {
+        language: Language::Arabic,
+        units: &[
+            "",           // 0
+            "واحد",       // 1
+            "اثنان",      // 2
+            "ثلاثة",       // 3
+            "أربعة",      // 4
+            "خمسة",       // 5
+            "ستة",        // 6
+            "سبعة",       // 7
+            "ثمانية",     // 8
+            "تسعة",       // 9
+            "عشرة",       // 10
+            "أحد عشر",    // 11
+            "اثنا عشر",   // 12
+            "ثلاثة عشر",   // 13
+            "أربعة عشر",  // 14
+            "خمسة عشر",   // 15
+            "ستة عشر",    // 16
+            "سبعة عشر",   // 17
+            "ثمانية عشر", // 18
+            "تسعة عشر",   // 19
+        ],
+        tens: &[
+            "",       // 0
+            "",       // 10 (handled in units)
+            "عشرون",  // 20
+            "ثلاثون",  // 30
+            "أربعون", // 40
+            "خمسون",  // 50
+            "ستون",   // 60
+            "سبعون",  // 70
+            "ثمانون", // 80
+            "تسعون",  // 90
+        ],
+        // Single words: the dual for 200 and the feminine-less unit stem joined to
+        // "مائة" for 300-900
+        hundreds: &[
+            "",         // 0
+            "مائة",     // 100
+            "مائتان",   // 200
+            "ثلاثمائة",  // 300
+            "أربعمائة", // 400
+            "خمسمائة",  // 500
+            "ستمائة",   // 600
+            "سبعمائة",  // 700
+            "ثمانمائة", // 800
+            "تسعمائة",  // 900
+        ],
+        scales: &[
+            ScaleWord {
+                value: 1_000_000_000_000_000_000,
+                singular: "كوينتيليون",
+                plural: "كوينتيليونات",
+                dual: Some("كوينتيليونان"),
+            },
+            ScaleWord {
+                value: 1_000_000_000_000_000,
+                singular: "كوادريليون",
+                plural: "كوادريليونات",
+                dual: Some("كوادريليونان"),
+            },
+            ScaleWord {
+                value: 1_000_000_000_000,
+                singular: "تريليون",
+                plural: "تريليونات",
+                dual: Some("تريليونان"),
+            },
+            ScaleWord {
+                value: 1_000_000_000,
+                singular: "مليار",
+                plural: "مليارات",
+                dual: Some("ملياران"),
+            },
+            ScaleWord {
+                value: 1_000_000,
+                singular: "مليون",
+                plural: "ملايين",
+                dual: Some("مليونان"),
+            },
+        ],
+        thousand: ScaleWord {
+            value: 1_000,
+            singular: "ألف",
+            plural: "آلاف",
+            dual: Some("ألفان"),
+        },
+        hundred: "مائة",
+        zero: "صفر",
+        minus: "سالب",
+        and: "و",
+        and_placement: AndPlacement::EveryGroup,
+        point: "فاصلة",
+        conjunction: "و",
+        #[cfg(feature = "std")]
+        only: "فقط",
+        quarters: ["و ربع", "و نصف", "و ثلاثة أرباع"],
+        thousands_separator: ',',
+    };
+
+    /// Supported languages for number conversion
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum Language {
+        /// English ("en")
+        #[default]
+        English,
+        /// Spanish ("es")
+        Spanish,
+        /// Arabic ("ar")
+        Arabic,
+    }
+
+    impl Language {
+        /// The canonical code of the language ("en", "es", "ar")
+        pub fn code(self) -> &'static str {
+            self.into()
+        }
+    }
+
+    /// Parses a language code, name or locale tag ("es", "Spanish", "es-MX")
+    ///
+    /// Accepts exactly what the `*_lang` functions accept: unknown languages and those
+    /// compiled out via Cargo features are `NumberConversionError::UnsupportedLanguage`.
+    impl TryFrom<&str> for Language {
+        type Error = NumberConversionError;
+
+        fn try_from(lang: &str) -> Result<Self, Self::Error> {
+            Ok(get_language_words(lang)?.language)
+        }
+    }
+
+    impl FromStr for Language {
+        type Err = NumberConversionError;
+
+        fn from_str(lang: &str) -> Result<Self, Self::Err> {
+            Language::try_from(lang)
+        }
+    }
+
+    impl From<Language> for &str {
+        fn from(lang: Language) -> Self {
+            match lang {
+                Language::English => "en",
+                Language::Spanish => "es",
+                Language::Arabic => "ar",
+            }
+        }
+    }
+
+    /// Canonical codes of the languages compiled into this build
+    const SUPPORTED_LANGUAGES: &[&str] = &[
+        "en",
+        #[cfg(feature = "lang-es")]
+        "es",
+        #[cfg(feature = "lang-ar")]
+        "ar",
+    ];
+
+    /// The canonical codes of the languages numbers can be spelled in, English first
+    ///
+    /// Spanish and Arabic are only listed when their Cargo features are enabled.
+    pub fn supported_languages() -> &'static [&'static str] {
+        SUPPORTED_LANGUAGES
+    }
+
+    /// Get the language-specific words based on the language code
+    ///
+    /// A locale tag with a region the tables don't distinguish falls back to its
+    /// language, so "es-MX" and "en_GB" use the Spanish and English words. Languages
+    /// whose Cargo feature (`lang-es`, `lang-ar`) is disabled are reported as
+    /// unsupported.
+    fn get_language_words(lang: &str) -> Result<&'static LanguageWords, NumberConversionError> {
+        let code = lang.to_lowercase();
+        let words = language_words(&code)
+            .or_else(|| language_words(code.split(['-', '_']).next()?))
+            .ok_or_else(|| NumberConversionError::UnsupportedLanguage(lang.to_string()))?;
+        debug_assert!(
+            words.units.len() >= 20 && words.tens.len() >= 10 && words.hundreds.len() >= 10,
+            "incomplete word table for {}",
+            lang
+        );
+        Ok(words)
+    }
+
+    /// The word table for a lowercase language code or name
+    fn language_words(code: &str) -> Option<&'static LanguageWords> {
+        match code {
+            "en" | "eng" | "english" => Some(&EN_WORDS),
+            #[cfg(feature = "lang-es")]
+            "es" | "esp" | "spanish" => Some(&ES_WORDS),
+            #[cfg(feature = "lang-ar")]
+            "ar" | "ara" | "arabic" => Some(&AR_WORDS),
+            _ => None,
+        }
+    }
+
+    /// Looks up a word in a language table, reporting a table that is too short as a
+    /// `ConversionError` instead of panicking
+    fn table_word(
+        table: &[&'static str],
+        index: i64,
+        name: &str,
+    ) -> Result<&'static str, NumberConversionError> {
+        table
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| NumberConversionError::ConversionError {
+                context: format!("Incomplete language table: no {} word for {}", name, index),
+                source: Box::new(NumberConversionError::InvalidInput(format!(
+                    "{} table has {} entries",
+                    name,
+                    table.len()
+                ))),
+            })
+    }
+
+    /// Checks that text can be produced in the given language
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::UnsupportedLanguage` for unknown codes and for
+    /// languages compiled out via Cargo features.
+    pub fn check_language(lang: &str) -> Result<(), NumberConversionError> {
+        get_language_words(lang).map(|_| ())
+    }
+
+    /// Get the thousands separator used when writing numerals in the given language
+    /// (',' for English, '.' for Spanish)
+    pub fn grouping_separator(lang: &str) -> Result<char, NumberConversionError> {
+        Ok(get_language_words(lang)?.thousands_separator)
+    }
+
+    /// Rewrites a numeral with Eastern Arabic-Indic digits
+    ///
+    /// ASCII digits become ٠-٩ and the ',' and '.' separators become the Arabic thousands
+    /// (٬) and decimal (٫) separators, so "1,234,567" returns "١٬٢٣٤٬٥٦٧".
+    pub fn to_arabic_digits(numeral: &str) -> String {
+        numeral
+            .chars()
+            .map(|c| match c {
+                '0'..='9' => char::from_u32('٠' as u32 + (c as u32 - '0' as u32))
+                    .expect("Arabic-Indic digits are contiguous"),
+                ',' => '٬',
+                '.' => '٫',
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// Formats a number as digits grouped in threes with the given separator
+    ///
+    /// `format_grouped(-1234567, ',')` returns "-1,234,567".
+    pub fn format_grouped(number: i64, separator: char) -> String {
+        let digits = number.unsigned_abs().to_string();
+        let mut result = String::new();
+
+        if number < 0 {
+            result.push('-');
+        }
+
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                result.push(separator);
+            }
+            result.push(digit);
+        }
+
+        result
+    }
+
+    /// Spells the number of decimal digits in a number, ignoring the sign
+    ///
+    /// 12345 returns "Five" and -42 returns "Two"; zero has one digit.
+    pub fn digit_count_to_text(number: i64) -> Result<String, NumberConversionError> {
+        let digits = number.unsigned_abs().to_string().len();
+        number_to_text(digits as i64)
+    }
+
+    /// Spells a string of digits one digit at a time in the given language, keeping
+    /// leading zeros ("007" reads "Zero Zero Seven")
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` if `digits` is empty or contains
+    /// anything other than ASCII digits, and `UnsupportedLanguage` for unknown codes.
+    pub fn to_digits_text(digits: &str, lang: &str) -> Result<String, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Not a digit sequence: {}",
+                digits
+            )));
+        }
+        Ok(spell_digits(digits, words))
+    }
+
+    /// Spells a card number digit by digit, followed by "(valid)" or "(invalid)"
+    /// according to its Luhn checksum
+    ///
+    /// Spaces and hyphens between digit groups are ignored, so "4242 4242 4242 4242"
+    /// is accepted.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` if the number has no digits or
+    /// contains anything other than digits, spaces and hyphens.
+    pub fn card_number_to_text(number: &str) -> Result<String, NumberConversionError> {
+        let digits = number
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .collect::<String>();
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Invalid card number: {}",
+                number
+            )));
+        }
+
+        // Double every second digit from the right, subtracting 9 from results above 9
+        let checksum: u32 = digits
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(position, digit)| {
+                let digit = u32::from(digit - b'0');
+                match position % 2 {
+                    0 => digit,
+                    _ if digit > 4 => digit * 2 - 9,
+                    _ => digit * 2,
+                }
+            })
+            .sum();
+        let validity = if checksum.is_multiple_of(10) {
+            "valid"
+        } else {
+            "invalid"
+        };
+
+        Ok(format!(
+            "{} ({})",
+            spell_digits(&digits, &EN_WORDS),
+            validity
+        ))
+    }
+
+    /// NATO phonetic alphabet words for A-Z
+    const NATO_ALPHABET: [&str; 26] = [
+        "Alpha", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India",
+        "Juliet", "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra",
+        "Tango", "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+    ];
+
+    /// Spells an alphanumeric code one character at a time, digits as words and
+    /// letters with the NATO phonetic alphabet ("1A2B" becomes "One Alpha Two Bravo")
+    ///
+    /// Letters are case-insensitive; spaces and hyphens between groups are skipped.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for an empty code or any other
+    /// character.
+    pub fn digits_to_phonetic(code: &str) -> Result<String, NumberConversionError> {
+        let words = code
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .map(|c| match c {
+                '0'..='9' => Ok(spell_digits(c.encode_utf8(&mut [0; 4]), &EN_WORDS)),
+                'a'..='z' | 'A'..='Z' => {
+                    Ok(NATO_ALPHABET[(c.to_ascii_uppercase() as u8 - b'A') as usize].to_string())
+                }
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Cannot spell '{}' in {}",
+                    c, code
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if words.is_empty() {
+            return Err(NumberConversionError::InvalidInput(
+                "Nothing to spell".to_string(),
+            ));
+        }
+        Ok(words.join(" "))
+    }
+
+    /// International Morse code for the digits 0-9
+    const MORSE_DIGITS: [&str; 10] = [
+        "-----", ".----", "..---", "...--", "....-", ".....", "-....", "--...", "---..", "----.",
+    ];
+
+    /// Writes the digits of a numeral in International Morse code, one space between
+    /// characters
+    ///
+    /// A minus sign becomes "-....-" and a ',' thousands separator becomes "/", so
+    /// "-1,234" returns "-....- .---- / ..--- ...-- ....-". Other characters are skipped.
+    pub fn to_morse(s: &str) -> String {
+        s.chars()
+            .filter_map(|c| match c {
+                '0'..='9' => Some(MORSE_DIGITS[c as usize - '0' as usize]),
+                '-' => Some("-....-"),
+                ',' => Some("/"),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Spells the binary digits of a number ("One Zero One Zero" for 10)
+    ///
+    /// Negative numbers use sign and magnitude rather than two's complement, so -10
+    /// reads "Minus One Zero One Zero".
+    pub fn binary_repr_to_text(number: i64) -> String {
+        let digits = spell_digits(&format!("{:b}", number.unsigned_abs()), &EN_WORDS);
+        if number < 0 {
+            format!("{} {}", EN_WORDS.minus, digits)
+        } else {
+            digits
+        }
+    }
+
+    /// Spells exact multiples of twenty as scores and of twelve as dozens, up to twelve
+    /// of either ("Three Score" for 60, "Two Dozen" for 24)
+    ///
+    /// Scores win when both apply; any other number is spelled as a plain cardinal,
+    /// so 37 stays "Thirty Seven".
+    pub fn to_text_with_groupings(number: i64) -> Result<String, NumberConversionError> {
+        for (size, name) in [(20, "Score"), (12, "Dozen")] {
+            if number > 0 && number % size == 0 && number / size <= 12 {
+                return Ok(format!("{} {}", number_to_text(number / size)?, name));
+            }
+        }
+        number_to_text(number)
+    }
+
+    /// Decimal byte units from bytes upwards, each 1000 times the previous
+    const DECIMAL_BYTE_UNITS: [&str; 7] = [
+        "Byte", "Kilobyte", "Megabyte", "Gigabyte", "Terabyte", "Petabyte", "Exabyte",
+    ];
+    /// Binary byte units from bytes upwards, each 1024 times the previous
+    const BINARY_BYTE_UNITS: [&str; 7] = [
+        "Byte", "Kibibyte", "Mebibyte", "Gibibyte", "Tebibyte", "Pebibyte", "Exbibyte",
+    ];
+
+    /// Spells a storage size in the largest unit holding at least one, with up to two
+    /// decimal places
+    ///
+    /// `binary` selects KiB/MiB/GiB (powers of 1024) over KB/MB/GB (powers of 1000), so
+    /// 1_048_576 bytes is "One Mebibyte" in binary and 1_500_000_000 is "One Point Five
+    /// Gigabytes" in decimal.
+    pub fn bytes_to_text(bytes: u64, binary: bool) -> Result<String, NumberConversionError> {
+        let (base, units) = if binary {
+            (1024_u128, BINARY_BYTE_UNITS)
+        } else {
+            (1000_u128, DECIMAL_BYTE_UNITS)
+        };
+
+        // Work in hundredths of the unit so rounding can carry into the next unit
+        let mut exponent = 0;
+        let mut divisor = 1_u128;
+        while exponent + 1 < units.len() as u32 && u128::from(bytes) >= divisor * base {
+            exponent += 1;
+            divisor *= base;
+        }
+        let mut hundredths = (u128::from(bytes) * 100 + divisor / 2) / divisor;
+        if hundredths >= base * 100 && exponent + 1 < units.len() as u32 {
+            exponent += 1;
+            divisor *= base;
+            hundredths = (u128::from(bytes) * 100 + divisor / 2) / divisor;
+        }
+
+        let whole = (hundredths / 100) as i64;
+        let fraction = format!("{:02}", hundredths % 100);
+        let fraction = fraction.trim_end_matches('0');
+
+        let mut result = join_decimal(
+            false,
+            whole,
+            &spell_digits(fraction, &EN_WORDS),
+            Casing::Title,
+        )?;
+        result.push(' ');
+        result.push_str(units[exponent as usize]);
+        if hundredths != 100 {
+            result.push('s');
+        }
+        Ok(result)
+    }
+
+    /// Reads a 24-hour "HHMM" time in military style, with "Zero" for leading zeros
+    ///
+    /// "0830" returns "Zero Eight Thirty Hours" and "1300" "Thirteen Hundred Hours".
+    pub fn military_time_to_text(time: &str) -> Result<String, NumberConversionError> {
+        military_time_to_text_with(time, ZeroWord::Zero)
+    }
+
+    /// Reads a 24-hour "HHMM" time in military style with the given word for leading
+    /// zeros ("Oh Eight Hundred Hours" with `ZeroWord::Oh`)
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` unless `time` is four digits with
+    /// hours 00-23 and minutes 00-59.
+    pub fn military_time_to_text_with(
+        time: &str,
+        zero: ZeroWord,
+    ) -> Result<String, NumberConversionError> {
+        let invalid = || {
+            NumberConversionError::InvalidInput(format!(
+                "Invalid military time: {} (expected HHMM)",
+                time
+            ))
+        };
+        if time.len() != 4 || !time.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let hours = time[..2].parse::<i64>().map_err(|_| invalid())?;
+        let minutes = time[2..].parse::<i64>().map_err(|_| invalid())?;
+        if hours > 23 || minutes > 59 {
+            return Err(invalid());
+        }
+
+        // Each half is read as a two-digit pair: "Zero Eight", "Zero Five", "Thirteen"
+        let pair = |value: i64| -> Result<String, NumberConversionError> {
+            match value {
+                0 => Ok(zero.as_str().to_string()),
+                1..=9 => Ok(format!("{} {}", zero.as_str(), number_to_text(value)?)),
+                _ => number_to_text(value),
+            }
+        };
+
+        let (hours, minutes) = match (hours, minutes) {
+            (_, 0) => (pair(hours)?, "Hundred".to_string()),
+            // "0005" reads both zeros of the hour: "Zero Zero Zero Five"
+            (0, _) => (format!("{0} {0}", zero.as_str()), pair(minutes)?),
+            _ => (pair(hours)?, pair(minutes)?),
+        };
+        Ok(format!("{} {} Hours", hours, minutes))
+    }
+
+    /// Reads an hour on a clock face with its clock-face Roman numeral ("Three O'Clock
+    /// (III)", "Four O'Clock (IIII)")
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for hours outside 1-12.
+    pub fn clock_position_to_text(hour: u8) -> Result<String, NumberConversionError> {
+        if !(1..=12).contains(&hour) {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Clock hours run from 1 to 12, got {}",
+                hour
+            )));
+        }
+        let hour = i64::from(hour);
+        Ok(format!(
+            "{} O'Clock ({})",
+            number_to_text(hour)?,
+            to_roman_clock(hour)?
+        ))
+    }
+
+    /// How fractions of a second are read by `duration_to_text`
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum DurationStyle {
+        /// Whole seconds followed by milliseconds ("One Second and Five Hundred
+        /// Milliseconds")
+        #[default]
+        Milliseconds,
+        /// Quarters and halves of a second as fractions ("One and a Half Seconds");
+        /// other fractions fall back to milliseconds
+        Fraction,
+    }
+
+    impl FromStr for DurationStyle {
+        type Err = NumberConversionError;
+
+        fn from_str(style: &str) -> Result<Self, Self::Err> {
+            match style.to_lowercase().as_str() {
+                "milliseconds" | "ms" => Ok(DurationStyle::Milliseconds),
+                "fraction" => Ok(DurationStyle::Fraction),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown duration style: {}",
+                    style
+                ))),
+            }
+        }
+    }
+
+    /// Spells a duration given in seconds as hours, minutes, seconds and the fraction
+    /// of a second, rounded to the millisecond
+    ///
+    /// 90.001 reads "One Minute, Thirty Seconds and One Millisecond"; `style` decides
+    /// whether 1.5 reads "One Second and Five Hundred Milliseconds" or "One and a Half
+    /// Seconds".
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for negative durations and
+    /// `NumberConversionError::DecimalError` for non-finite or oversized ones.
+    #[cfg(feature = "std")]
+    pub fn duration_to_text(
+        seconds: f64,
+        style: DurationStyle,
+    ) -> Result<String, NumberConversionError> {
+        // Checked in milliseconds, the unit the duration is rounded to
+        check_decimal(seconds * 1000.0)?;
+        if seconds < 0.0 {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Duration cannot be negative: {}",
+                seconds
+            )));
+        }
+
+        let total_millis = (seconds * 1000.0).round() as i64;
+        let (whole, millis) = (total_millis / 1000, total_millis % 1000);
+        let counted = |count: i64, unit: &str| -> Result<String, NumberConversionError> {
+            Ok(format!(
+                "{} {}",
+                number_to_text(count)?,
+                plural_form(unit, count, &EN_WORDS)
+            ))
+        };
+
+        let mut parts = Vec::new();
+        for (count, unit) in [(whole / 3600, "Hour"), (whole / 60 % 60, "Minute")] {
+            if count > 0 {
+                parts.push(counted(count, unit)?);
+            }
+        }
+        let secs = whole % 60;
+        if style == DurationStyle::Fraction && millis > 0 && millis % 250 == 0 {
+            parts.push(match (secs, millis / 250) {
+                (0, 1) => "A Quarter of a Second".to_string(),
+                (0, 2) => "Half a Second".to_string(),
+                (0, _) => "Three Quarters of a Second".to_string(),
+                (secs, quarters) => format!(
+                    "{}{} Seconds",
+                    number_to_text(secs)?,
+                    quarters_suffix(quarters as u64)
+                ),
+            });
+        } else {
+            if secs > 0 || (parts.is_empty() && millis == 0) {
+                parts.push(counted(secs, "Second")?);
+            }
+            if millis > 0 {
+                parts.push(counted(millis, "Millisecond")?);
+            }
+        }
+
+        Ok(join_list(&parts, "and"))
+    }
+
+    const EN_MONTHS: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    #[cfg(feature = "lang-es")]
+    const ES_MONTHS: [&str; 12] = [
+        "Enero",
+        "Febrero",
+        "Marzo",
+        "Abril",
+        "Mayo",
+        "Junio",
+        "Julio",
+        "Agosto",
+        "Septiembre",
+        "Octubre",
+        "Noviembre",
+        "Diciembre",
+    ];
+
+    /// Spells a date with the month name and the day in the language's usual form
+    ///
+    /// English uses the ordinal day ("The Fifteenth of March, Two Thousand Twenty
+    /// Four"); Spanish uses the cardinal day except for the first ("Primero de Marzo de
+    /// Dos Mil Veinte y Cuatro").
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for a month outside 1-12 or a day
+    /// the month doesn't have, and `NumberConversionError::UnsupportedLanguage` for
+    /// languages without month names (currently anything but English and Spanish).
+    pub fn date_to_text(
+        year: i64,
+        month: u32,
+        day: u32,
+        lang: &str,
+    ) -> Result<String, NumberConversionError> {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => {
+                return Err(NumberConversionError::InvalidInput(format!(
+                    "Invalid month: {}",
+                    month
+                )))
+            }
+        };
+        if day == 0 || day > days_in_month {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Invalid day: {} (month {} has {} days)",
+                day, month, days_in_month
+            )));
+        }
+
+        let words = get_language_words(lang)?;
+        let month_index = month as usize - 1;
+        if words.language == Language::English {
+            return Ok(format!(
+                "The {} of {}, {}",
+                to_ordinal_words(i64::from(day))?,
+                EN_MONTHS[month_index],
+                number_to_text(year)?
+            ));
+        }
+
+        #[cfg(feature = "lang-es")]
+        if words.language == Language::Spanish {
+            let day = match day {
+                1 => "Primero".to_string(),
+                _ => number_to_text_lang(i64::from(day), lang)?,
+            };
+            return Ok(format!(
+                "{} de {} de {}",
+                day,
+                ES_MONTHS[month_index],
+                number_to_text_lang(year, lang)?
+            ));
+        }
+
+        Err(NumberConversionError::UnsupportedLanguage(lang.to_string()))
+    }
+
+    /// Spells a number rounded to one significant figure, prefixed with "About"
+    ///
+    /// The rounding step grows with the magnitude, so 42 reads "About Forty",
+    /// 950 "About One Thousand" and 1_234_567 "About One Million".
+    pub fn approximate_to_text(number: i64) -> Result<String, NumberConversionError> {
+        let magnitude = u128::from(number.unsigned_abs());
+        let step = 10_u128.pow(magnitude.checked_ilog10().unwrap_or(0));
+        let rounded = (magnitude + step / 2) / step * step;
+        let rounded =
+            i64::try_from(rounded).map_err(|_| NumberConversionError::ValueTooLarge(number))?;
+
+        let sign = if number < 0 { -1 } else { 1 };
+        Ok(format!("About {}", number_to_text(sign * rounded)?))
+    }
+
+    /// Spells a number in the compact form used on social media, rounded to one
+    /// decimal of its largest scale
+    ///
+    /// 1500 reads "One Point Five K", 2_300_000 "Two Point Three Million" and 999_950
+    /// rounds up to "One Million"; numbers below one thousand are spelled in full.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::ValueTooLarge` for the same values as
+    /// [`number_to_text`], `i64::MIN` included.
+    pub fn short_form_to_text(number: i64) -> Result<String, NumberConversionError> {
+        let value = magnitude(number)?;
+        if value >= i64::MAX / 2 {
+            return Err(NumberConversionError::ValueTooLarge(number));
+        }
+        let magnitude = value as u128;
+        let Some(index) = SCALE_UNITS
+            .iter()
+            .position(|&(divisor, _)| magnitude >= divisor as u128)
+        else {
+            return number_to_text(number);
+        };
+
+        let tenths_of = |divisor: i64| (magnitude * 10 + divisor as u128 / 2) / divisor as u128;
+        let (mut divisor, mut unit) = SCALE_UNITS[index];
+        // Rounding can carry into the next scale (999_950 is 1000.0 K)
+        if tenths_of(divisor) >= 10_000 && index > 0 {
+            (divisor, unit) = SCALE_UNITS[index - 1];
+        }
+        let tenths = tenths_of(divisor);
+
+        let tenth = (tenths % 10) as usize;
+        let fraction = if tenth == 0 {
+            ""
+        } else {
+            EN_WORDS.units[tenth]
+        };
+        let mut result = join_decimal(number < 0, (tenths / 10) as i64, fraction, Casing::Title)?;
+        result.push(' ');
+        result.push_str(if unit == "Thousand" { "K" } else { unit });
+        Ok(result)
+    }
+
+    /// Spells a number in trading-floor shorthand, reading quarters of its largest scale
+    /// as fractions
+    ///
+    /// 1_500_000 reads "One and a Half Million", 2_250_000 "Two and a Quarter Million"
+    /// and 3_750_000 "Three and Three Quarters Million". Numbers below one thousand, or
+    /// that are not a whole number of quarters of their scale, are spelled in full.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::ValueTooLarge` for the same values as
+    /// [`number_to_text`], `i64::MIN` included.
+    pub fn trader_reading_to_text(number: i64) -> Result<String, NumberConversionError> {
+        let magnitude = number.unsigned_abs();
+        if magnitude >= (i64::MAX / 2) as u64 {
+            return Err(NumberConversionError::ValueTooLarge(number));
+        }
+        let Some(&(divisor, unit)) = SCALE_UNITS
+            .iter()
+            .find(|&&(divisor, _)| magnitude >= divisor as u64)
+        else {
+            return number_to_text(number);
+        };
+
+        let quarter = divisor as u64 / 4;
+        if !magnitude.is_multiple_of(quarter) {
+            return number_to_text(number);
+        }
+        let fraction = quarters_suffix(magnitude % divisor as u64 / quarter);
+
+        let mut result = String::new();
+        if number < 0 {
+            result.push_str(EN_WORDS.minus);
+            result.push(' ');
+        }
+        result.push_str(&number_to_text((magnitude / divisor as u64) as i64)?);
+        result.push_str(&fraction);
+        result.push(' ');
+        result.push_str(unit);
+        Ok(result)
+    }
+
+    /// Words following a whole number to add 0-3 quarters (" and a Half")
+    fn quarters_suffix(quarters: u64) -> String {
+        match quarters {
+            0 => String::new(),
+            _ => format!(" {}", EN_WORDS.quarters[quarters.min(3) as usize - 1]),
+        }
+    }
+
+    /// Spells the prime factorization of a positive number, smallest factor first
+    ///
+    /// 12 returns "Two times Two times Three"; 1 and primes are spelled as themselves.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for zero and negative numbers.
+    pub fn prime_factors_to_text(number: i64) -> Result<String, NumberConversionError> {
+        if number < 1 {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Prime factorization needs a positive number, got {}",
+                number
+            )));
+        }
+        if number == 1 {
+            return number_to_text(1);
+        }
+
+        let mut factors = Vec::new();
+        let mut remaining = number;
+        let mut divisor = 2;
+        while divisor <= remaining / divisor {
+            while remaining % divisor == 0 {
+                factors.push(number_to_text(divisor)?);
+                remaining /= divisor;
+            }
+            divisor += if divisor == 2 { 1 } else { 2 };
+        }
+        if remaining > 1 {
+            factors.push(number_to_text(remaining)?);
+        }
+
+        Ok(factors.join(" times "))
+    }
+
+    /// Returns the highest scale word used when spelling a number, ignoring the sign
+    ///
+    /// 1_234_567 returns `Some("Million")`; numbers below one thousand return `None`.
+    pub fn largest_scale(number: i64) -> Option<&'static str> {
+        let magnitude = number.unsigned_abs();
+        SCALE_UNITS
+            .iter()
+            .find(|&&(divisor, _)| magnitude >= divisor as u64)
+            .map(|&(_, unit)| unit)
+    }
+
+    /// Splits the magnitude of a number into its groups of three digits, largest first,
+    /// each paired with the scale word it is spelled with ("" for the last group)
+    ///
+    /// 1_234_567 returns `[(1, "Million"), (234, "Thousand"), (567, "")]`; groups
+    /// below the highest are kept even when zero.
+    pub fn number_groups(number: i64) -> Vec<(u64, &'static str)> {
+        number_groups_with(number, ScaleSystem::Short)
+    }
+
+    /// Like [`number_groups`], but splits at the scale words of `system`
+    ///
+    /// 12_345_678 in the Indian system returns
+    /// `[(1, "Crore"), (23, "Lakh"), (45, "Thousand"), (678, "")]`.
+    pub fn number_groups_with(number: i64, system: ScaleSystem) -> Vec<(u64, &'static str)> {
+        let mut remaining = number.unsigned_abs();
+        let mut groups = Vec::new();
+        for &(divisor, unit) in system.table() {
+            let divisor = divisor as u64;
+            if remaining >= divisor || !groups.is_empty() {
+                groups.push((remaining / divisor, unit));
+                remaining %= divisor;
+            }
+        }
+        groups.push((remaining, ""));
+        groups
+    }
+
+    /// Splits the English spelling of a number into subtitle lines of at most
+    /// `max_chars` characters
+    ///
+    /// Lines break after scale words, so each scale group ("Two Hundred and Thirty Four
+    /// Thousand") stays on one line when it fits; groups that fit together share a line
+    /// and a group longer than `max_chars` is wrapped between words. A single word
+    /// longer than `max_chars` gets a line of its own.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` if `max_chars` is zero.
+    pub fn subtitle_lines(
+        number: i64,
+        max_chars: usize,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        if max_chars == 0 {
+            return Err(NumberConversionError::InvalidInput(
+                "Subtitle lines need room for at least one character".to_string(),
+            ));
+        }
+
+        let mut groups = Vec::new();
+        let mut group = String::new();
+        for segment in number_to_text_segments(number)? {
+            append_word(&mut group, &segment);
+            if segment != "Hundred" && is_scale_word(&segment) {
+                groups.push(core::mem::take(&mut group));
+            }
+        }
+        if !group.is_empty() {
+            groups.push(group);
+        }
+
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        for group in groups {
+            if !line.is_empty() && line.len() + 1 + group.len() <= max_chars {
+                append_word(&mut line, &group);
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(core::mem::take(&mut line));
+            }
+            // Wrap an oversized group between its words
+            for word in group.split(' ') {
+                if !line.is_empty() && line.len() + 1 + word.len() > max_chars {
+                    lines.push(core::mem::take(&mut line));
+                }
+                append_word(&mut line, word);
+            }
+        }
+        lines.push(line);
+        Ok(lines)
+    }
+
+    /// Appends `word` to `text`, separated by a space unless `text` is empty
+    fn append_word(text: &mut String, word: &str) {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(word);
+    }
+
+    /// The short-scale table used for English, largest divisor first
+    pub fn scale_table() -> &'static [(i64, &'static str)] {
+        &SCALE_UNITS
+    }
+
+    /// Converts a number to its textual representation in English.
+    ///
+    /// # Arguments
+    /// * `number` - The number to convert
+    ///
+    /// # Returns
+    /// * `Result<String, NumberConversionError>` - The textual representation or an error
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::ValueTooLarge` if the number is too large to convert.
+    /// Returns `NumberConversionError::InvalidInput` if the input is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// # use number_to_text::number_to_text;
+    /// let text = number_to_text(42)?;
+    /// assert_eq!(text, "Forty Two");
+    /// # Ok::<(), number_to_text::NumberConversionError>(())
+    /// ```
+    pub fn number_to_text(number: i64) -> Result<String, NumberConversionError> {
+        number_to_text_with_options(number, &ConversionOptions::default())
+    }
+
+    /// Converts a number to its textual representation in English using the given options
+    pub fn number_to_text_with_options(
+        number: i64,
+        options: &ConversionOptions,
+    ) -> Result<String, NumberConversionError> {
+        let mut text = join_words(&english_segments(number, options)?, options);
+        if number < 0 && options.sign_placement == SignPlacement::Parentheses {
+            text = format!("({})", text);
+        }
+        Ok(options.bidi.apply(text))
+    }
+
+    /// Converts a number into its English word segments (e.g. "Minus", "One Hundred",
+    /// "and", "Five", "Thousand") without joining them
+    ///
+    /// `number_to_text` is the space-joined form of these segments.
+    pub fn number_to_text_segments(number: i64) -> Result<Vec<String>, NumberConversionError> {
+        english_segments(number, &ConversionOptions::default())
+    }
+
+    fn english_segments(
+        number: i64,
+        options: &ConversionOptions,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        if number == 0 {
+            let zero = options.zero_word.map_or(EN_WORDS.zero, ZeroWord::as_str);
+            return Ok(vec![zero.to_string()]);
+        }
+
+        let scales = options.scale_system.table();
+        let mut words = convert(magnitude(number)?, scales)?;
+
+        if options.scale_system == ScaleSystem::Indian
+            && options.indian_labels != IndianLabels::Words
+        {
+            let mut counts = scale_counts(magnitude(number)?, scales).into_iter();
+            for word in words.iter_mut() {
+                if !scales.iter().any(|&(_, unit)| unit == word) {
+                    continue;
+                }
+                let count = counts.next().unwrap_or_default();
+                let label = match (word.as_str(), options.indian_labels) {
+                    ("Lakh", IndianLabels::Abbreviated) => "L".to_string(),
+                    ("Crore", IndianLabels::Abbreviated) => "Cr".to_string(),
+                    (label @ ("Lakh" | "Crore"), IndianLabels::Plural) => {
+                        plural_form(label, count, &EN_WORDS)
+                    }
+                    _ => continue,
+                };
+                *word = label;
+            }
+        }
+
+        match options.and_style {
+            AndStyle::Hundreds => {}
+            AndStyle::British => {
+                let last_group = number.abs() % 1000;
+                if number.abs() >= 1000 && (1..100).contains(&last_group) {
+                    let group_len = convert_small_number(last_group)?.len();
+                    words.insert(words.len() - group_len, EN_WORDS.and.to_string());
+                }
+            }
+            AndStyle::Omit => words.retain(|word| word != EN_WORDS.and),
+        }
+
+        if options.hyphenate {
+            words = hyphenate_tens(words);
+        }
+
+        let magnitude = number.unsigned_abs();
+        let is_single_scale = magnitude == 100
+            || scales
+                .iter()
+                .any(|&(divisor, _)| magnitude == divisor as u64);
+        if options.indefinite_article && is_single_scale {
+            words[0] = words[0].replacen("One", "A", 1);
+        }
+
+        if number < 0 {
+            let negative_word = options.negative_word.as_str().to_string();
+            match options.sign_placement {
+                SignPlacement::Prefix => words.insert(0, negative_word),
+                SignPlacement::Suffix => words.push(negative_word),
+                // Wrapped after joining, so casing sees the bare words
+                SignPlacement::Parentheses => {}
+            }
+        }
+
+        Ok(words)
+    }
+
+    /// Joins each tens word with the unit word following it ("Twenty", "One" becomes
+    /// "Twenty-One")
+    fn hyphenate_tens(words: Vec<String>) -> Vec<String> {
+        let mut joined: Vec<String> = Vec::with_capacity(words.len());
+        for word in words {
+            let follows_tens = joined
+                .last()
+                .is_some_and(|last| EN_WORDS.tens[2..].contains(&last.as_str()));
+            if follows_tens && EN_WORDS.units[1..10].contains(&word.as_str()) {
+                let last = joined.last_mut().expect("checked above");
+                last.push('-');
+                last.push_str(&word);
+            } else {
+                joined.push(word);
+            }
+        }
+        joined
+    }
+
+    /// Joins word parts with spaces, applying the configured casing to each word
+    fn join_words(words: &[String], options: &ConversionOptions) -> String {
+        let words = words.iter().flat_map(|part| part.split(' '));
+
+        match options.casing {
+            Casing::Title => words.collect::<Vec<_>>().join(" "),
+            Casing::ScaleWords => words
+                .map(|word| {
+                    if is_scale_word(word) {
+                        word.to_string()
+                    } else {
+                        word.to_lowercase()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+            Casing::Lower => words
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join(" "),
+            Casing::SentenceScales => words
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        let lower = word.to_lowercase();
+                        let mut chars = lower.chars();
+                        chars
+                            .next()
+                            .map(|first| first.to_uppercase().chain(chars).collect())
+                            .unwrap_or_default()
+                    } else if word != "Hundred" && is_scale_word(word) {
+                        word.to_string()
+                    } else {
+                        word.to_lowercase()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    /// Returns true for English scale words (Hundred, Thousand, Million, ...)
+    fn is_scale_word(word: &str) -> bool {
+        word == "Hundred"
+            || SCALE_UNITS
+                .iter()
+                .chain(&LONG_SCALE_UNITS)
+                .chain(&INDIAN_SCALE_UNITS)
+                .any(|&(_, unit)| unit == word)
+    }
+
+    /// Writes the English textual representation of a number directly into `writer`.
+    ///
+    /// The words are still collected by `convert`, but they are written one at a time
+    /// instead of being joined into a `String` first.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` of kind `InvalidInput` wrapping the `NumberConversionError`
+    /// if the number cannot be converted, or any error raised by `writer`.
+    #[cfg(feature = "std")]
+    pub fn write_number_to<W: Write>(writer: &mut W, number: i64) -> io::Result<()> {
+        if number == 0 {
+            return writer.write_all(EN_WORDS.zero.as_bytes());
+        }
+
+        let words = magnitude(number)
+            .and_then(|magnitude| convert(magnitude, &SCALE_UNITS))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        if number < 0 {
+            writer.write_all(EN_WORDS.minus.as_bytes())?;
+            writer.write_all(b" ")?;
+        }
+
+        for (index, word) in words.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b" ")?;
+            }
+            writer.write_all(word.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Converts a number to English words, grouping it by the given scale table
+    ///
+    /// `scales` lists `(divisor, word)` pairs, largest first, so an Indian table of
+    /// `[(10_000_000, "Crore"), (100_000, "Lakh"), (1000, "Thousand")]` spells
+    /// 12_000_000 as "One Crore Twenty Lakh". Each group is spelled below one thousand.
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` unless the divisors are strictly
+    /// decreasing, above one and end at or below one thousand, and
+    /// `NumberConversionError::ValueTooLarge` if the largest group reaches one thousand.
+    pub fn convert_with_scales(
+        number: i64,
+        scales: &[(i64, &str)],
+    ) -> Result<String, NumberConversionError> {
+        let decreasing = scales.windows(2).all(|pair| pair[0].0 > pair[1].0);
+        let valid = match scales.last() {
+            Some(&(smallest, _)) => decreasing && smallest > 1 && smallest <= 1000,
+            None => false,
+        };
+        if !valid {
+            return Err(NumberConversionError::InvalidInput(
+                "Scale divisors must decrease from largest to smallest and end at or below 1000"
+                    .to_string(),
+            ));
+        }
+
+        let magnitude = number.unsigned_abs();
+        if magnitude >= (i64::MAX / 2) as u64 || magnitude / scales[0].0 as u64 >= 1000 {
+            return Err(NumberConversionError::ValueTooLarge(number));
+        }
+        if number == 0 {
+            return Ok(EN_WORDS.zero.to_string());
+        }
+
+        let words = convert(magnitude as i64, scales)?.join(" ");
+        Ok(if number < 0 {
+            format!("{} {}", EN_WORDS.minus, words)
+        } else {
+            words
+        })
+    }
+
+    /// A piece of a number as split by `scale_pieces`
+    enum ScalePiece<'a> {
+        /// A count that still needs scale words of its own
+        Number(i64),
+        /// A count below one thousand, with the scale word it counts ("" for the last)
+        Group(i64, &'a str),
+        /// A scale word with the whole count it follows
+        Scale(&'a str, i64),
+    }
+
+    /// Splits a number into its groups and scale words, largest first
+    ///
+    /// A count of a thousand or more, which the Indian crore allows, is pushed back
+    /// onto a work stack and split in turn, so no input recurses.
+    fn scale_pieces<'a>(number: i64, scales: &[(i64, &'a str)]) -> Vec<ScalePiece<'a>> {
+        let mut pieces = Vec::new();
+        let mut stack = vec![ScalePiece::Number(number)];
+
+        while let Some(piece) = stack.pop() {
+            let ScalePiece::Number(number) = piece else {
+                pieces.push(piece);
+                continue;
+            };
+
+            // The stack is last in, first out, so the parts are pushed in reverse
+            let mut parts = Vec::new();
+            let mut remaining = number;
+            for &(divisor, unit) in scales {
+                let quotient = remaining / divisor;
+                if quotient == 0 {
+                    continue;
+                }
+                parts.push(if quotient >= 1000 {
+                    ScalePiece::Number(quotient)
+                } else {
+                    ScalePiece::Group(quotient, unit)
+                });
+                parts.push(ScalePiece::Scale(unit, quotient));
+                remaining %= divisor;
+            }
+            parts.push(ScalePiece::Group(remaining, ""));
+            stack.extend(parts.into_iter().rev());
+        }
+        pieces
+    }
+
+    /// Converts a number into its constituent word parts.
+    ///
+    /// Scale groups are visited from largest to smallest (see `scale_pieces`), each
+    /// spelled below one thousand. [`convert_with_scales`] exposes it with a custom
+    /// scale table.
+    ///
+    /// # Arguments
+    /// * `number` - The positive number to convert
+    /// * `scales` - The scale words with their divisors, largest first
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, NumberConversionError>` - Vector of word parts or an error
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::ValueTooLarge` if the number is too large to convert.
+    fn convert(number: i64, scales: &[(i64, &str)]) -> Result<Vec<String>, NumberConversionError> {
+        convert_groups(number, scales, small_number_words)
+    }
+
+    /// `convert` with each group below one thousand spelled by `spell_group`
+    fn convert_groups(
+        number: i64,
+        scales: &[(i64, &str)],
+        spell_group: fn(i64) -> Result<Vec<String>, NumberConversionError>,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        if number >= i64::MAX / 2 {
+            return Err(NumberConversionError::ValueTooLarge(number));
+        }
+
+        let mut words = Vec::new();
+        for piece in scale_pieces(number, scales) {
+            match piece {
+                ScalePiece::Group(count, unit) => {
+                    words.extend(spell_group(count).map_err(|e| {
+                        NumberConversionError::ConversionError {
+                            context: if unit.is_empty() {
+                                "Failed to convert small number".to_string()
+                            } else {
+                                format!("Failed to convert {} group", unit)
+                            },
+                            source: Box::new(e),
+                        }
+                    })?);
+                }
+                ScalePiece::Scale(unit, _) => words.push(unit.to_string()),
+                ScalePiece::Number(_) => {}
+            }
+        }
+        Ok(words)
+    }
+
+    /// Benchmark hook: the word parts of a non-negative number, with its groups read
+    /// from the `small_number_words` cache or spelled afresh by `convert_small_number`
+    /// (the same path without `std`, which has no cache)
+    #[cfg(feature = "bench")]
+    #[doc(hidden)]
+    pub fn convert_for_bench(
+        number: i64,
+        cached: bool,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        let spell_group = if cached {
+            small_number_words
+        } else {
+            convert_small_number
+        };
+        convert_groups(number, &SCALE_UNITS, spell_group)
+    }
+
+    /// Returns the value counted by each scale word `convert` emits for `number`, in
+    /// the same order ("Twenty One Lakh" counts 21)
+    fn scale_counts(number: i64, scales: &[(i64, &str)]) -> Vec<i64> {
+        scale_pieces(number, scales)
+            .into_iter()
+            .filter_map(|piece| match piece {
+                ScalePiece::Scale(_, count) => Some(count),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the words for a number below 1000, cached after the first call
+    ///
+    /// Every group of a large number is spelled this way, so batch conversions reuse
+    /// the same thousand entries instead of rebuilding them.
+    #[cfg(feature = "std")]
+    fn small_number_words(number: i64) -> Result<Vec<String>, NumberConversionError> {
+        static CACHE: OnceLock<Vec<Vec<String>>> = OnceLock::new();
+
+        if !(0..1000).contains(&number) {
+            return convert_small_number(number);
+        }
+        let cache = CACHE.get_or_init(|| {
+            (0..1000)
+                .map(|n| convert_small_number(n).expect("numbers below 1000 convert"))
+                .collect()
+        });
+        Ok(cache[number as usize].clone())
+    }
+
+    /// Returns the words for a number below 1000 (uncached without `std`)
+    #[cfg(not(feature = "std"))]
+    fn small_number_words(number: i64) -> Result<Vec<String>, NumberConversionError> {
+        convert_small_number(number)
+    }
+
+    /// Converts a number less than 1000 to words.
+    ///
+    /// # Arguments
+    /// * `number` - The number to convert (must be less than 1000)
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, NumberConversionError>` - Vector of word parts or an error
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` if number >= 1000.
+    fn convert_small_number(number: i64) -> Result<Vec<String>, NumberConversionError> {
+        if number >= 1000 {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Number {} is too large for convert_small_number (must be < 1000)",
+                number
+            )));
+        }
+
+        let mut words = Vec::new();
+
+        if number >= 100 {
+            words.push(format!(
+                "{} {}",
+                EN_WORDS.units[(number / 100) as usize],
+                EN_WORDS.hundred
+            ));
+        }
+
+        let remainder = number % 100;
+
+        if remainder > 0 {
+            if !words.is_empty() {
+                words.push(EN_WORDS.and.to_string());
+            }
+
+            if remainder < 20 {
+                words.push(EN_WORDS.units[remainder as usize].to_string());
+            } else {
+                words.push(EN_WORDS.tens[(remainder / 10) as usize].to_string());
+                if remainder % 10 > 0 {
+                    words.push(EN_WORDS.units[(remainder % 10) as usize].to_string());
+                }
+            }
+        }
+
+        Ok(words)
+    }
+
+    /// Rounds a decimal to `places` fractional digits and splits it into its sign, whole
+    /// part and all `places` digits after the point (-1.5 at two places is
+    /// `(true, 1, "50")`)
+    ///
+    /// The sign is dropped when the value rounds to zero. Callers bound the magnitude
+    /// first, so the scaled value fits in a `u128`.
+    fn split_decimal(number: f64, places: u32) -> (bool, i64, String) {
+        let scale = 10_u128.pow(places);
+        // Adding a half before truncating rounds half away from zero without `f64::round`
+        let scaled = (number.abs() * scale as f64 + 0.5) as u128;
+        let digits = format!("{:0width$}", scaled % scale, width = places as usize);
+        (number < 0.0 && scaled > 0, (scaled / scale) as i64, digits)
+    }
+
+    /// Spells an English decimal from its sign, whole part and the already spelled
+    /// fraction, joined by the table's point word
+    ///
+    /// `casing` applies to the point word only: `Casing::Title` capitalizes it to sit
+    /// among capitalized words ("One Point Five Meters"), anything else keeps the
+    /// table's lowercase "point". An empty fraction leaves the whole part on its own.
+    fn join_decimal(
+        negative: bool,
+        whole: i64,
+        fraction: &str,
+        casing: Casing,
+    ) -> Result<String, NumberConversionError> {
+        let mut result = String::new();
+        // `number_to_text` signs the whole part itself, which loses the sign above -1
+        if negative {
+            result.push_str(EN_WORDS.minus);
+            result.push(' ');
+        }
+        result.push_str(&number_to_text(whole)?);
+
+        if !fraction.is_empty() {
+            result.push(' ');
+            if casing == Casing::Title {
+                let mut chars = EN_WORDS.point.chars();
+                if let Some(first) = chars.next() {
+                    result.extend(first.to_uppercase());
+                    result.push_str(chars.as_str());
+                }
+            } else {
+                result.push_str(EN_WORDS.point);
+            }
+            result.push(' ');
+            result.push_str(fraction);
+        }
+
+        Ok(result)
+    }
+
+    /// Converts a decimal number to its textual representation, rounded to two decimal
+    /// places whose value is read as a number ("Forty Two point Forty Two")
+    #[cfg(feature = "std")]
+    pub fn decimal_to_text(number: f64) -> Result<String, NumberConversionError> {
+        check_decimal(number)?;
+
+        let (negative, whole, hundredths) = split_decimal(number, 2);
+        let fraction = match hundredths.parse().unwrap_or_default() {
+            0 => String::new(),
+            hundredths => number_to_text(hundredths)?,
+        };
+        join_decimal(negative, whole, &fraction, Casing::Lower)
+    }
+
+    /// Converts a decimal number to text in the specified language, rounded to two
+    /// decimal places
+    ///
+    /// English is read exactly as `decimal_to_text` reads it. In other languages the
+    /// fractional digits are read one by one after the language's separator word, so
+    /// -3.5 in Spanish is "Menos Tres coma Cinco". Arabic text is built in logical
+    /// (reading) order, so 3.05 is "ثلاثة فاصلة صفر خمسة" and displays right to left.
+    #[cfg(feature = "std")]
+    pub fn decimal_to_text_lang(number: f64, lang: &str) -> Result<String, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        if words.language == Language::English {
+            return decimal_to_text(number);
+        }
+        check_decimal(number)?;
+
+        let (negative, integer_part, fraction) = split_decimal(number, 2);
+        let fraction = fraction.trim_end_matches('0');
+
+        let mut result = Vec::new();
+        if negative {
+            result.push(words.minus.to_string());
+        }
+        result.extend(number_to_text_lang_segments(integer_part, lang)?);
+        if !fraction.is_empty() {
+            result.push(words.point.to_string());
+            result.push(spell_digits(fraction, words));
+        }
+
+        Ok(result.join(" "))
+    }
+
+    /// Converts a decimal number to text without rounding, reading up to `precision`
+    /// fractional digits one by one ("Three point One Four")
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::DecimalError` if the number has more fractional
+    /// digits than `precision`, so 3.14159 is rejected at precision 2 instead of being
+    /// silently rounded.
+    #[cfg(feature = "std")]
+    pub fn decimal_to_text_strict(
+        number: f64,
+        precision: usize,
+    ) -> Result<String, NumberConversionError> {
+        check_decimal(number)?;
+
+        // `Display` prints the shortest form that round-trips, so no digits are invented
+        let written = number.abs().to_string();
+        let fraction = written.split_once('.').map_or("", |(_, fraction)| fraction);
+        if fraction.len() > precision {
+            return Err(NumberConversionError::DecimalError(format!(
+                "{} has {} fractional digits, more than the precision of {}",
+                number,
+                fraction.len(),
+                precision
+            )));
+        }
+
+        join_decimal(
+            number < 0.0,
+            number.trunc().abs() as i64,
+            &spell_digits(fraction, &EN_WORDS),
+            Casing::Lower,
+        )
+    }
+
+    /// Converts a decimal ending in .25, .5 or .75 to a whole number and a fraction in
+    /// the given language ("Three and a Half", "Dos y Cuarto")
+    ///
+    /// Any other decimal, and any below one, falls back to `decimal_to_text_lang`.
+    #[cfg(feature = "std")]
+    pub fn decimal_to_fraction_text(
+        number: f64,
+        lang: &str,
+    ) -> Result<String, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        check_decimal(number)?;
+
+        let quarters = number.abs().fract() * 4.0;
+        if number.abs() < 1.0 || quarters == 0.0 || quarters.fract() != 0.0 {
+            return decimal_to_text_lang(number, lang);
+        }
+
+        let mut result = number_to_text_lang_segments(number.trunc() as i64, lang)?;
+        result.push(words.quarters[quarters as usize - 1].to_string());
+        Ok(result.join(" "))
+    }
+
+    /// Spells a measurement with its unit, rounded to two decimal places ("One Meter",
+    /// "One Point Five Meters")
+    ///
+    /// The singular is only used for exactly one (after rounding); zero, fractions and
+    /// everything else take the plural, as in "Zero Meters".
+    #[cfg(feature = "std")]
+    pub fn measurement_to_text(
+        value: f64,
+        unit_singular: &str,
+        unit_plural: &str,
+    ) -> Result<String, NumberConversionError> {
+        check_decimal(value)?;
+
+        let (negative, whole, hundredths) = split_decimal(value, 2);
+        let fraction = hundredths.trim_end_matches('0');
+        // Units are capitalized, so the point word is too
+        let number = join_decimal(
+            negative,
+            whole,
+            &spell_digits(fraction, &EN_WORDS),
+            Casing::Title,
+        )?;
+        let unit = if fraction.is_empty() && is_singular(whole) {
+            unit_singular
+        } else {
+            unit_plural
+        };
+        Ok(format!("{} {}", number, unit))
+    }
+
+    /// Rejects decimals that cannot be converted: NaN, infinities and integer parts at
+    /// or beyond the `i64::MAX / 2` ceiling that applies to integers
+    ///
+    /// Both are reported as `NumberConversionError::DecimalError`, so callers can tell a
+    /// malformed float apart from an integer that is out of range.
+    #[cfg(feature = "std")]
+    fn check_decimal(number: f64) -> Result<(), NumberConversionError> {
+        if !number.is_finite() {
+            return Err(NumberConversionError::DecimalError(format!(
+                "{} is not a finite number",
+                number
+            )));
+        }
+        // `as` saturates instead of failing, so reject large magnitudes up front
+        if number.trunc().abs() >= (i64::MAX / 2) as f64 {
+            return Err(NumberConversionError::DecimalError(format!(
+                "Integer part of {} is too large to convert",
+                number
+            )));
+        }
+        Ok(())
+    }
+
+    /// Spells a latitude/longitude pair with hemisphere words
+    ///
+    /// Each coordinate keeps up to six decimal places, read digit by digit, so
+    /// (40.7, -74.0) becomes "Forty Point Seven North, Seventy Four Point Zero West".
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` if a coordinate is not finite or
+    /// lies outside ±90° latitude / ±180° longitude.
+    pub fn coordinate_to_text(lat: f64, lon: f64) -> Result<String, NumberConversionError> {
+        let latitude = spell_coordinate(lat, 90.0, ("North", "South"))?;
+        let longitude = spell_coordinate(lon, 180.0, ("East", "West"))?;
+        Ok(format!("{}, {}", latitude, longitude))
+    }
+
+    /// Spells one coordinate followed by its positive or negative hemisphere word
+    fn spell_coordinate(
+        degrees: f64,
+        limit: f64,
+        (positive, negative): (&str, &str),
+    ) -> Result<String, NumberConversionError> {
+        if !degrees.is_finite() || degrees.abs() > limit {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Coordinate {} is outside ±{}°",
+                degrees, limit
+            )));
+        }
+
+        // The hemisphere carries the sign, and whole degrees still read "Point Zero"
+        let (_, whole, millionths) = split_decimal(degrees, 6);
+        let fraction = match millionths.trim_end_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+        let number = join_decimal(
+            false,
+            whole,
+            &spell_digits(fraction, &EN_WORDS),
+            Casing::Title,
+        )?;
+
+        Ok(format!(
+            "{} {}",
+            number,
+            if degrees < 0.0 { negative } else { positive }
+        ))
+    }
+
+    /// Maximum number of fractional digits produced when expanding a fraction
+    const MAX_FRACTION_DIGITS: usize = 64;
+
+    /// Spells a repeating decimal written with a trailing "..." (e.g. "0.333...")
+    ///
+    /// The shortest block repeating at the end of the written digits is read once and
+    /// followed by "Repeating", so "0.1666..." becomes "Zero point One Six Repeating".
+    pub fn repeating_decimal_to_text(input: &str) -> Result<String, NumberConversionError> {
+        let invalid =
+            || NumberConversionError::InvalidInput(format!("Invalid repeating decimal: {}", input));
+
+        let digits = input.trim().strip_suffix("...").ok_or_else(invalid)?;
+        let (negative, digits) = match digits.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, digits),
+        };
+        let (integer, fraction) = digits.split_once('.').ok_or_else(invalid)?;
+
+        let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(integer) || !all_digits(fraction) {
+            return Err(invalid());
+        }
+
+        let integer = integer.parse::<i64>().map_err(|_| invalid())?;
+        let (fixed, repetend) = split_repetend(fraction);
+        spell_decimal_expansion(negative, integer, fixed, repetend)
+    }
+
+    /// Expands `numerator / denominator` as a decimal and spells it, reading a repeating
+    /// block once followed by "Repeating" (1/3 becomes "Zero point Three Repeating")
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for a zero denominator or when the
+    /// expansion does not terminate or repeat within 64 digits.
+    pub fn fraction_to_decimal_text(
+        numerator: i64,
+        denominator: i64,
+    ) -> Result<String, NumberConversionError> {
+        if denominator == 0 {
+            return Err(NumberConversionError::InvalidInput(
+                "Denominator cannot be zero".to_string(),
+            ));
+        }
+
+        let negative = numerator != 0 && (numerator < 0) != (denominator < 0);
+        let numerator = numerator.unsigned_abs() as u128;
+        let denominator = denominator.unsigned_abs() as u128;
+        let integer = i64::try_from(numerator / denominator)
+            .map_err(|_| NumberConversionError::ValueTooLarge(i64::MAX))?;
+
+        // Long division, remembering which remainder produced each digit so that the
+        // first repeated remainder marks the start of the repeating block
+        let mut remainder = numerator % denominator;
+        let mut digits = String::new();
+        let mut remainders = Vec::new();
+
+        while remainder != 0 {
+            if let Some(start) = remainders.iter().position(|&seen| seen == remainder) {
+                return spell_decimal_expansion(
+                    negative,
+                    integer,
+                    &digits[..start],
+                    &digits[start..],
+                );
+            }
+            if digits.len() == MAX_FRACTION_DIGITS {
+                return Err(NumberConversionError::InvalidInput(format!(
+                    "Decimal expansion of {}/{} exceeds {} digits",
+                    numerator, denominator, MAX_FRACTION_DIGITS
+                )));
+            }
+
+            remainders.push(remainder);
+            remainder *= 10;
+            digits.push(char::from(b'0' + (remainder / denominator) as u8));
+            remainder %= denominator;
+        }
+
+        spell_decimal_expansion(negative, integer, &digits, "")
+    }
+
+    /// Splits written fractional digits into the non-repeating part and the shortest
+    /// block that repeats (at least twice) through to the end
+    fn split_repetend(fraction: &str) -> (&str, &str) {
+        let digits = fraction.as_bytes();
+
+        for start in 0..digits.len() {
+            let tail = &digits[start..];
+            for period in 1..=tail.len() / 2 {
+                if (period..tail.len()).all(|i| tail[i] == tail[i - period]) {
+                    return (&fraction[..start], &fraction[start..start + period]);
+                }
+            }
+        }
+
+        ("", fraction)
+    }
+
+    /// Spells a decimal expansion from its integer part, non-repeating fractional digits
+    /// and repeating block (empty when the expansion terminates)
+    fn spell_decimal_expansion(
+        negative: bool,
+        integer: i64,
+        fixed: &str,
+        repetend: &str,
+    ) -> Result<String, NumberConversionError> {
+        let mut result = String::new();
+        if negative {
+            result.push_str(EN_WORDS.minus);
+            result.push(' ');
+        }
+        result.push_str(&number_to_text(integer)?);
+
+        if !fixed.is_empty() || !repetend.is_empty() {
+            result.push(' ');
+            result.push_str(EN_WORDS.point);
+            result.push(' ');
+            result.push_str(&spell_digits(&[fixed, repetend].concat(), &EN_WORDS));
+        }
+        if !repetend.is_empty() {
+            result.push_str(" Repeating");
+        }
+
+        Ok(result)
+    }
+
+    /// Spells a string of ASCII digits one digit at a time ("105" becomes "One Zero Five")
+    fn spell_digits(digits: &str, words: &LanguageWords) -> String {
+        digits
+            .bytes()
+            .map(|digit| match digit - b'0' {
+                0 => words.zero,
+                digit => words.units[digit as usize],
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Converts a number to its words followed by the numeric ordinal
+    /// ("Twenty One (21st)")
+    pub fn to_ordinal(number: i64) -> Result<String, NumberConversionError> {
+        let words = number_to_text(number)?;
+        Ok(format!("{} ({})", words, to_ordinal_numeric(number)))
+    }
+
+    /// The English ordinal suffix for a number: "st", "nd", "rd" or "th"
+    ///
+    /// Numbers ending in 11, 12 and 13 take "th" (11th, 112th).
+    pub fn ordinal_suffix(number: i64) -> &'static str {
+        let number = number.unsigned_abs();
+        match (number % 10, number % 100) {
+            (1, 11) | (2, 12) | (3, 13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
+
+    /// Writes a number with its ordinal suffix ("42nd")
+    pub fn to_ordinal_numeric(number: i64) -> String {
+        format!("{}{}", number, ordinal_suffix(number))
+    }
+
+    /// Converts a number to its ordinal form in words (First, Twenty Second, One Hundredth, etc)
+    ///
+    /// Only the final word takes the ordinal suffix, so compound numbers read naturally:
+    /// 101 becomes "One Hundred and First" and 1000 becomes "One Thousandth".
+    pub fn to_ordinal_words(number: i64) -> Result<String, NumberConversionError> {
+        nth_word(number)
+    }
+
+    /// The English ordinal word for a position: "First", "Second", ... "Hundredth"
+    ///
+    /// All ordinal words are built here. Negative numbers keep the cardinal's sign
+    /// word, so -1 reads "Minus First".
+    pub fn nth_word(number: i64) -> Result<String, NumberConversionError> {
+        let words = number_to_text(number)?;
+
+        let (head, last) = match words.rsplit_once(' ') {
+            Some((head, last)) => (Some(head), last),
+            None => (None, words.as_str()),
+        };
+
+        let last = ordinal_word(last);
+        Ok(match head {
+            Some(head) => format!("{} {}", head, last),
+            None => last,
+        })
+    }
+
+    /// Converts a single cardinal word to its ordinal form
+    fn ordinal_word(word: &str) -> String {
+        match word {
+            "One" => "First".to_string(),
+            "Two" => "Second".to_string(),
+            "Three" => "Third".to_string(),
+            "Five" => "Fifth".to_string(),
+            "Eight" => "Eighth".to_string(),
+            "Nine" => "Ninth".to_string(),
+            "Twelve" => "Twelfth".to_string(),
+            _ => match word.strip_suffix('y') {
+                Some(stem) => format!("{}ieth", stem),
+                None => format!("{}th", word),
+            },
+        }
+    }
+
+    /// Spanish masculine ordinals for 1-19
+    #[cfg(feature = "lang-es")]
+    const ES_ORDINAL_UNITS: [&str; 20] = [
+        "",
+        "Primero",
+        "Segundo",
+        "Tercero",
+        "Cuarto",
+        "Quinto",
+        "Sexto",
+        "Séptimo",
+        "Octavo",
+        "Noveno",
+        "Décimo",
+        "Undécimo",
+        "Duodécimo",
+        "Decimotercero",
+        "Decimocuarto",
+        "Decimoquinto",
+        "Decimosexto",
+        "Decimoséptimo",
+        "Decimoctavo",
+        "Decimonoveno",
+    ];
+
+    #[cfg(feature = "lang-es")]
+    const ES_ORDINAL_TENS: [&str; 10] = [
+        "",
+        "",
+        "Vigésimo",
+        "Trigésimo",
+        "Cuadragésimo",
+        "Quincuagésimo",
+        "Sexagésimo",
+        "Septuagésimo",
+        "Octogésimo",
+        "Nonagésimo",
+    ];
+
+    /// Arabic masculine ordinals for 1-19; in compounds 1 becomes "الحادي"
+    #[cfg(feature = "lang-ar")]
+    const AR_ORDINAL_UNITS: [&str; 20] = [
+        "",
+        "الأول",
+        "الثاني",
+        "الثالث",
+        "الرابع",
+        "الخامس",
+        "السادس",
+        "السابع",
+        "الثامن",
+        "التاسع",
+        "العاشر",
+        "الحادي عشر",
+        "الثاني عشر",
+        "الثالث عشر",
+        "الرابع عشر",
+        "الخامس عشر",
+        "السادس عشر",
+        "السابع عشر",
+        "الثامن عشر",
+        "التاسع عشر",
+    ];
+
+    #[cfg(feature = "lang-ar")]
+    const AR_ORDINAL_TENS: [&str; 10] = [
+        "",
+        "",
+        "العشرون",
+        "الثلاثون",
+        "الأربعون",
+        "الخمسون",
+        "الستون",
+        "السبعون",
+        "الثمانون",
+        "التسعون",
+    ];
+
+    /// Grammatical form of a Spanish ordinal
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum OrdinalForm {
+        /// "Primero", "Tercero"
+        #[default]
+        Masculine,
+        /// "Primera", "Tercera"
+        Feminine,
+        /// The shortened masculine used before a noun: "Primer", "Tercer"
+        Short,
+    }
+
+    impl FromStr for OrdinalForm {
+        type Err = NumberConversionError;
+
+        fn from_str(form: &str) -> Result<Self, Self::Err> {
+            match form.to_lowercase().as_str() {
+                "masculine" => Ok(OrdinalForm::Masculine),
+                "feminine" => Ok(OrdinalForm::Feminine),
+                "short" => Ok(OrdinalForm::Short),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown ordinal form: {}",
+                    form
+                ))),
+            }
+        }
+    }
+
+    /// Converts a number to its ordinal form in words in the specified language
+    ///
+    /// English accepts any number (see [`to_ordinal_words`]). Spanish and Arabic use the
+    /// masculine form for 1-99: "Vigésimo Primero", "الحادي و العشرون".
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for Spanish and Arabic numbers
+    /// outside 1-99.
+    pub fn to_ordinal_lang(number: i64, lang: &str) -> Result<String, NumberConversionError> {
+        to_ordinal_lang_with(number, lang, OrdinalForm::Masculine)
+    }
+
+    /// Like [`to_ordinal_lang`], but with the Spanish ordinal in the given `form`
+    ///
+    /// The feminine changes every word ("Vigésima Primera"); the short form only
+    /// changes a final "Primero" or "Tercero" ("Vigésimo Primer", "Decimotercer").
+    /// English and Arabic ordinals are returned in their usual form.
+    ///
+    /// # Errors
+    /// Fails for the same inputs as [`to_ordinal_lang`].
+    #[cfg_attr(not(feature = "lang-es"), allow(unused_variables))]
+    pub fn to_ordinal_lang_with(
+        number: i64,
+        lang: &str,
+        form: OrdinalForm,
+    ) -> Result<String, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        if words.language == Language::English {
+            return to_ordinal_words(number);
+        }
+
+        if !(1..=99).contains(&number) {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Ordinals in {} are supported from 1 to 99, got {}",
+                lang, number
+            )));
+        }
+
+        #[cfg(feature = "lang-es")]
+        if words.language == Language::Spanish {
+            let (tens, units) = ((number / 10) as usize, (number % 10) as usize);
+            let text = match (tens, units) {
+                (0 | 1, _) => ES_ORDINAL_UNITS[number as usize].to_string(),
+                (_, 0) => ES_ORDINAL_TENS[tens].to_string(),
+                _ => format!("{} {}", ES_ORDINAL_TENS[tens], ES_ORDINAL_UNITS[units]),
+            };
+            return Ok(match form {
+                OrdinalForm::Masculine => text,
+                OrdinalForm::Feminine => text
+                    .split(' ')
+                    .map(|word| match word.strip_suffix('o') {
+                        Some(stem) => format!("{}a", stem),
+                        None => word.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                OrdinalForm::Short if text.ends_with("mero") || text.ends_with("cero") => {
+                    text[..text.len() - 1].to_string()
+                }
+                OrdinalForm::Short => text,
+            });
+        }
+
+        #[cfg(feature = "lang-ar")]
+        if words.language == Language::Arabic {
+            let (tens, units) = ((number / 10) as usize, (number % 10) as usize);
+            return Ok(match (tens, units) {
+                (0 | 1, _) => AR_ORDINAL_UNITS[number as usize].to_string(),
+                (_, 0) => AR_ORDINAL_TENS[tens].to_string(),
+                (_, 1) => format!("الحادي {} {}", words.and, AR_ORDINAL_TENS[tens]),
+                _ => format!(
+                    "{} {} {}",
+                    AR_ORDINAL_UNITS[units], words.and, AR_ORDINAL_TENS[tens]
+                ),
+            });
+        }
+
+        Err(NumberConversionError::UnsupportedLanguage(lang.to_string()))
+    }
+
+    /// Returns the form of a counted noun that agrees with `count` in the given
+    /// language
+    ///
+    /// English adds "s" except for irregular nouns ("Penny" becomes "Pence", "Fils"
+    /// stays "Fils"); Spanish adds "s" after a vowel and "es" after a consonant,
+    /// dropping the accent of "-ón" ("Millón" becomes "Millones"). Arabic nouns are
+    /// returned unchanged, since their agreement depends on more than the plural.
+    pub fn pluralize(word: &str, count: i64, lang: &str) -> Result<String, NumberConversionError> {
+        Ok(plural_form(word, count, get_language_words(lang)?))
+    }
+
+    /// Whether a noun counted by `count` takes its singular form (only for one or
+    /// minus one)
+    fn is_singular(count: i64) -> bool {
+        count.unsigned_abs() == 1
+    }
+
+    fn plural_form(word: &str, count: i64, words: &LanguageWords) -> String {
+        if is_singular(count) {
+            return word.to_string();
+        }
+
+        #[cfg(feature = "lang-es")]
+        if words.language == Language::Spanish {
+            return match word {
+                // "Mil" counts thousands without changing ("Dos Mil")
+                "Mil" | "Fils" => word.to_string(),
+                "Dírham" => "Dírhams".to_string(),
+                _ => match word.strip_suffix("ón") {
+                    Some(stem) => format!("{}ones", stem),
+                    None if word.ends_with(['a', 'e', 'i', 'o', 'u']) => format!("{}s", word),
+                    None => format!("{}es", word),
+                },
+            };
+        }
+
+        if words.language == Language::English {
+            return match word {
+                "Penny" => "Pence".to_string(),
+                "Fils" => word.to_string(),
+                _ => format!("{}s", word),
+            };
+        }
+
+        word.to_string()
+    }
+
+    /// Currencies supported by the currency formatter
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum Currency {
+        /// US Dollar, 100 cents
+        #[default]
+        Usd,
+        /// Bahraini Dinar, 1000 fils
+        Bhd,
+        /// Saudi Riyal, 100 halalas
+        Sar,
+        /// UAE Dirham, 100 fils
+        Aed,
+        /// Euro, 100 cents
+        Eur,
+        /// Pound Sterling, 100 pence
+        Gbp,
+    }
+
+    impl Currency {
+        /// Number of decimal places used by the minor unit (2 for cents, 3 for fils)
+        pub fn minor_decimals(self) -> u32 {
+            match self {
+                Currency::Usd | Currency::Sar | Currency::Aed | Currency::Eur | Currency::Gbp => 2,
+                Currency::Bhd => 3,
+            }
+        }
+
+        /// English name of the major unit, pluralized with [`pluralize`]
+        #[cfg(feature = "std")]
+        fn major_unit(self) -> &'static str {
+            match self {
+                Currency::Usd => "Dollar",
+                Currency::Bhd => "Dinar",
+                Currency::Sar => "Riyal",
+                Currency::Aed => "Dirham",
+                Currency::Eur => "Euro",
+                Currency::Gbp => "Pound",
+            }
+        }
+
+        /// English name of the minor unit, pluralized with [`pluralize`]
+        #[cfg(feature = "std")]
+        fn minor_unit(self) -> &'static str {
+            match self {
+                Currency::Usd | Currency::Eur => "Cent",
+                Currency::Bhd | Currency::Aed => "Fils",
+                Currency::Sar => "Halala",
+                Currency::Gbp => "Penny",
+            }
+        }
+
+        /// Spanish name of the major unit and whether it is feminine
+        #[cfg(all(feature = "std", feature = "lang-es"))]
+        fn spanish_major_unit(self) -> (&'static str, bool) {
+            match self {
+                Currency::Usd => ("Dólar", false),
+                Currency::Bhd => ("Dinar", false),
+                Currency::Sar => ("Riyal", false),
+                Currency::Aed => ("Dírham", false),
+                Currency::Eur => ("Euro", false),
+                Currency::Gbp => ("Libra", true),
+            }
+        }
+
+        /// Spanish name of the minor unit and whether it is feminine
+        #[cfg(all(feature = "std", feature = "lang-es"))]
+        fn spanish_minor_unit(self) -> (&'static str, bool) {
+            match self {
+                Currency::Usd => ("Centavo", false),
+                Currency::Bhd | Currency::Aed => ("Fils", false),
+                Currency::Sar => ("Halala", true),
+                Currency::Eur => ("Céntimo", false),
+                Currency::Gbp => ("Penique", false),
+            }
+        }
+
+        /// Arabic singular, dual and plural names of the major unit
+        #[cfg(all(feature = "std", feature = "lang-ar"))]
+        fn arabic_major_unit(self) -> (&'static str, &'static str, &'static str) {
+            match self {
+                Currency::Usd => ("دولار", "دولاران", "دولارات"),
+                Currency::Bhd => ("دينار", "ديناران", "دنانير"),
+                Currency::Sar => ("ريال", "ريالان", "ريالات"),
+                Currency::Aed => ("درهم", "درهمان", "دراهم"),
+                Currency::Eur => ("يورو", "يورو", "يورو"),
+                Currency::Gbp => ("جنيه", "جنيهان", "جنيهات"),
+            }
+        }
+
+        /// Arabic singular, dual and plural names of the minor unit
+        #[cfg(all(feature = "std", feature = "lang-ar"))]
+        fn arabic_minor_unit(self) -> (&'static str, &'static str, &'static str) {
+            match self {
+                Currency::Usd | Currency::Eur => ("سنت", "سنتان", "سنتات"),
+                Currency::Bhd | Currency::Aed => ("فلس", "فلسان", "فلوس"),
+                Currency::Sar => ("هللة", "هللتان", "هللات"),
+                Currency::Gbp => ("بنس", "بنسان", "بنسات"),
+            }
+        }
+    }
+
+    /// Formats a number as currency
+    #[cfg(feature = "std")]
+    pub fn to_currency(number: f64) -> Result<String, NumberConversionError> {
+        to_currency_with(number, Currency::Usd)
+    }
+
+    /// Formats a number as an amount of the given currency
+    ///
+    /// The amount is rounded to the currency's minor decimals, so 1.004 BHD reads
+    /// "One Dinar and Four Fils" and 1.234 BHD "One Dinar and Two Hundred and Thirty
+    /// Four Fils".
+    #[cfg(feature = "std")]
+    pub fn to_currency_with(
+        number: f64,
+        currency: Currency,
+    ) -> Result<String, NumberConversionError> {
+        to_currency_with_options(number, currency, &ConversionOptions::default())
+    }
+
+    /// Formats a number as an amount of the given currency, marking negative amounts
+    /// with the options' negative word and sign placement
+    ///
+    /// With `SignPlacement::Suffix` the sign closes the amount, as some financial
+    /// formats require: -100 reads "One Hundred Dollars Minus". Accountants'
+    /// `SignPlacement::Parentheses` gives "(One Hundred Dollars)". Other options are
+    /// ignored.
+    #[cfg(feature = "std")]
+    pub fn to_currency_with_options(
+        number: f64,
+        currency: Currency,
+        options: &ConversionOptions,
+    ) -> Result<String, NumberConversionError> {
+        let (negative, integer_part, minor) = split_currency(number, currency)?;
+        let sign = options.negative_word.as_str();
+
+        let mut result = String::new();
+        if negative && options.sign_placement == SignPlacement::Prefix {
+            result.push_str(sign);
+            result.push(' ');
+        }
+        result.push_str(&number_to_text(integer_part)?);
+        result.push(' ');
+        result.push_str(&plural_form(currency.major_unit(), integer_part, &EN_WORDS));
+
+        if minor > 0 {
+            result.push_str(" and ");
+            result.push_str(&number_to_text(minor)?);
+            result.push(' ');
+            result.push_str(&plural_form(currency.minor_unit(), minor, &EN_WORDS));
+        }
+        if negative && options.sign_placement == SignPlacement::Suffix {
+            result.push(' ');
+            result.push_str(sign);
+        }
+        if negative && options.sign_placement == SignPlacement::Parentheses {
+            result = format!("({})", result);
+        }
+
+        Ok(result)
+    }
+
+    /// Formats a number as an amount of the given currency in the specified language
+    ///
+    /// Arabic nouns agree with their count: one takes the singular followed by "واحد",
+    /// two the dual alone, three to ten the plural, and other counts the singular, so
+    /// 2.45 SAR reads "ريالان و خمسة و أربعون هللة".
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::UnsupportedLanguage` for languages without
+    /// currency names (currently anything but English, Spanish and Arabic).
+    #[cfg(feature = "std")]
+    pub fn to_currency_lang(
+        number: f64,
+        currency: Currency,
+        lang: &str,
+    ) -> Result<String, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        if words.language == Language::English {
+            return to_currency_with(number, currency);
+        }
+
+        #[cfg(feature = "lang-ar")]
+        if words.language == Language::Arabic {
+            let (negative, integer_part, minor) = split_currency(number, currency)?;
+
+            let mut result = Vec::new();
+            if negative {
+                result.push(words.minus.to_string());
+            }
+            result.push(arabic_counted(integer_part, currency.arabic_major_unit())?);
+            if minor > 0 {
+                result.push(words.and.to_string());
+                result.push(arabic_counted(minor, currency.arabic_minor_unit())?);
+            }
+            return Ok(result.join(" "));
+        }
+
+        #[cfg(feature = "lang-es")]
+        if words.language == Language::Spanish {
+            let (negative, integer_part, minor) = split_currency(number, currency)?;
+
+            let mut result = Vec::new();
+            if negative {
+                result.push(words.minus.to_string());
+            }
+            result.push(spanish_counted(
+                integer_part,
+                currency.spanish_major_unit(),
+            )?);
+            if minor > 0 {
+                result.push(words.and.to_string());
+                result.push(spanish_counted(minor, currency.spanish_minor_unit())?);
+            }
+            return Ok(result.join(" "));
+        }
+
+        Err(NumberConversionError::UnsupportedLanguage(lang.to_string()))
+    }
+
+    /// Formats an amount for a check, closed with the language's "only" so nothing
+    /// can be appended ("One Hundred Dollars Only", "مائة ريال فقط")
+    ///
+    /// # Errors
+    /// Fails for the same inputs and languages as [`to_currency_lang`].
+    #[cfg(feature = "std")]
+    pub fn to_currency_only(
+        number: f64,
+        currency: Currency,
+        lang: &str,
+    ) -> Result<String, NumberConversionError> {
+        let amount = to_currency_lang(number, currency, lang)?;
+        Ok(format!("{} {}", amount, get_language_words(lang)?.only))
+    }
+
+    /// How a zero part of a check amount is written
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum CheckZero {
+        /// "Zero Dollars", "00/100"
+        #[default]
+        Zero,
+        /// "No Dollars", "No/100"
+        No,
+    }
+
+    impl FromStr for CheckZero {
+        type Err = NumberConversionError;
+
+        fn from_str(word: &str) -> Result<Self, Self::Err> {
+            match word.to_lowercase().as_str() {
+                "zero" => Ok(CheckZero::Zero),
+                "no" => Ok(CheckZero::No),
+                _ => Err(NumberConversionError::InvalidInput(format!(
+                    "Unknown check zero word: {}",
+                    word
+                ))),
+            }
+        }
+    }
+
+    /// Options for amounts written on checks
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct CheckOptions {
+        /// Wording of an amount below one major unit ("Zero Dollars", "No Dollars")
+        pub zero_dollars: CheckZero,
+        /// Wording of a whole amount's minor units ("00/100", "No/100")
+        pub zero_cents: CheckZero,
+    }
+
+    /// Formats an amount the way it is written on a check, with the major units in
+    /// words and the minor units as a fraction ("Twelve Dollars and 50/100")
+    ///
+    /// `options` choose the wording of a zero major part ("No Dollars and 50/100") and
+    /// of zero minor units ("Twelve Dollars and No/100").
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::InvalidInput` for negative amounts and fails
+    /// for the same inputs as [`to_currency_with`].
+    #[cfg(feature = "std")]
+    pub fn to_check_amount(
+        number: f64,
+        currency: Currency,
+        options: &CheckOptions,
+    ) -> Result<String, NumberConversionError> {
+        let (negative, major, minor) = split_currency(number, currency)?;
+        if negative {
+            return Err(NumberConversionError::InvalidInput(format!(
+                "Check amounts cannot be negative: {}",
+                number
+            )));
+        }
+
+        let major_words = match (major, options.zero_dollars) {
+            (0, CheckZero::No) => "No".to_string(),
+            _ => number_to_text(major)?,
+        };
+        let digits = currency.minor_decimals() as usize;
+        let minor_digits = match (minor, options.zero_cents) {
+            (0, CheckZero::No) => "No".to_string(),
+            _ => format!("{:0digits$}", minor),
+        };
+        Ok(format!(
+            "{} {} and {}/{}",
+            major_words,
+            plural_form(currency.major_unit(), major, &EN_WORDS),
+            minor_digits,
+            10_u32.pow(currency.minor_decimals())
+        ))
+    }
+
+    /// Rounds an amount to whole minor units of `currency`, returning the sign and the
+    /// major and minor parts
+    ///
+    /// The sign is split off so amounts below one major unit (e.g. -0.01) stay negative
+    /// even though their major part is zero.
+    #[cfg(feature = "std")]
+    fn split_currency(
+        number: f64,
+        currency: Currency,
+    ) -> Result<(bool, i64, i64), NumberConversionError> {
+        check_decimal(number)?;
+
+        let minor_per_major = 10_i128.pow(currency.minor_decimals());
+        let total_minor = (number * minor_per_major as f64).round() as i128;
+        let major = (total_minor.abs() / minor_per_major) as i64;
+        let minor = (total_minor.abs() % minor_per_major) as i64;
+        Ok((total_minor < 0, major, minor))
+    }
+
+    /// Spells a Spanish count followed by its noun in the agreeing form
+    ///
+    /// A final "Uno" agrees with the noun ("Un Dólar", "Veinte y Una Libras"), and
+    /// exact millions take "de" ("Un Millón de Dólares").
+    #[cfg(all(feature = "std", feature = "lang-es"))]
+    fn spanish_counted(
+        count: i64,
+        (noun, feminine): (&str, bool),
+    ) -> Result<String, NumberConversionError> {
+        let mut words = number_to_text_lang_segments(count, "es")?;
+        if let Some(last) = words.last_mut().filter(|last| *last == "Uno") {
+            *last = if feminine { "Una" } else { "Un" }.to_string();
+        }
+        if count >= 1_000_000 && count % 1_000_000 == 0 {
+            words.push("de".to_string());
+        }
+        words.push(plural_form(noun, count, &ES_WORDS));
+        Ok(words.join(" "))
+    }
+
+    /// Spells an Arabic count followed by its noun in the agreeing form
+    #[cfg(all(feature = "std", feature = "lang-ar"))]
+    fn arabic_counted(
+        count: i64,
+        (singular, dual, plural): (&str, &str, &str),
+    ) -> Result<String, NumberConversionError> {
+        Ok(match count {
+            1 => format!("{} {}", singular, AR_WORDS.units[1]),
+            2 => dual.to_string(),
+            _ => {
+                let noun = if (3..=10).contains(&(count % 100)) {
+                    plural
+                } else {
+                    singular
+                };
+                format!("{} {}", number_to_text_lang(count, "ar")?, noun)
+            }
+        })
+    }
+
+    /// Spells a list of numbers in English, joining the last two with "and"
+    ///
+    /// `[1, 2, 3]` returns "One, Two and Three"; an empty slice returns an empty string.
+    pub fn spell_list(numbers: &[i64]) -> Result<String, NumberConversionError> {
+        spell_list_lang(numbers, "en")
+    }
+
+    /// Spells a list of numbers in the specified language, joining the last two with
+    /// the language's conjunction ("Uno, Dos y Tres")
+    pub fn spell_list_lang(numbers: &[i64], lang: &str) -> Result<String, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        let items = numbers
+            .iter()
+            .map(|&number| number_to_text_lang(number, lang))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(join_list(&items, words.conjunction))
+    }
+
+    /// Spells a list of English ordinals for the given positions ("First, Second and
+    /// Third")
+    pub fn spell_ordinal_list(numbers: &[i64]) -> Result<String, NumberConversionError> {
+        let items = numbers
+            .iter()
+            .map(|&number| nth_word(number))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(join_list(&items, EN_WORDS.conjunction))
+    }
+
+    /// Adds a column spelling the integers of `column` (counted from 0) to every row of
+    /// a simple comma-separated table
+    ///
+    /// Cells are split on commas without quoting rules. Cells that are not integers,
+    /// such as a header, and rows too short to have the column get an empty spelled
+    /// cell; line endings and a trailing newline are kept.
+    ///
+    /// ```
+    /// # use number_to_text::spell_csv_column;
+    /// let csv = "item,qty\nbolts,12\n";
+    /// assert_eq!(
+    ///     spell_csv_column(csv, 1, "en")?,
+    ///     "item,qty,\nbolts,12,Twelve\n"
+    /// );
+    /// # Ok::<(), number_to_text::NumberConversionError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::UnsupportedLanguage` for unknown languages and
+    /// any error from spelling a cell, such as `ValueTooLarge`.
+    pub fn spell_csv_column(
+        csv: &str,
+        column: usize,
+        lang: &str,
+    ) -> Result<String, NumberConversionError> {
+        check_language(lang)?;
+
+        let mut result = String::with_capacity(csv.len() * 2);
+        for line in csv.split_inclusive('\n') {
+            let (row, ending) = match line.strip_suffix("\r\n") {
+                Some(row) => (row, "\r\n"),
+                None => match line.strip_suffix('\n') {
+                    Some(row) => (row, "\n"),
+                    None => (line, ""),
+                },
+            };
+            let spelled = match row.split(',').nth(column).map(|cell| cell.trim().parse()) {
+                Some(Ok(number)) => number_to_text_lang(number, lang)?,
+                _ => String::new(),
+            };
+            result.push_str(row);
+            result.push(',');
+            result.push_str(&spelled);
+            result.push_str(ending);
+        }
+        Ok(result)
+    }
+
+    /// Joins list items with commas and the conjunction before the last one
+    fn join_list(items: &[String], conjunction: &str) -> String {
+        match items.split_last() {
+            None => String::new(),
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} {} {}", rest.join(", "), conjunction, last),
+        }
+    }
+
+    /// Converts a number to its textual representation in the specified language
+    pub fn number_to_text_lang(number: i64, lang: &str) -> Result<String, NumberConversionError> {
+        Ok(number_to_text_lang_segments(number, lang)?.join(" "))
+    }
+
+    /// Converts a number into its word segments in the specified language
+    ///
+    /// This is the unjoined form of `number_to_text_lang`.
+    pub fn number_to_text_lang_segments(
+        number: i64,
+        lang: &str,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        let words = get_language_words(lang)?;
+        if words.language == Language::English {
+            return number_to_text_segments(number);
+        }
+        lang_segments(number, words)
+    }
+
+    /// Word segments of a number in a language other than English
+    fn lang_segments(
+        number: i64,
+        words: &LanguageWords,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        if number == 0 {
+            return Ok(vec![words.zero.to_string()]);
+        }
+
+        let mut result = Vec::new();
+
+        if number < 0 {
+            result.push(words.minus.to_string());
+        }
+
+        result.extend(convert_with_lang(magnitude(number)?, words)?);
+        Ok(result)
+    }
+
+    /// The absolute value of a number to spell
+    ///
+    /// # Errors
+    /// Returns `NumberConversionError::ValueTooLarge` for `i64::MIN`, whose absolute
+    /// value does not fit in an `i64`.
+    fn magnitude(number: i64) -> Result<i64, NumberConversionError> {
+        number
+            .checked_abs()
+            .ok_or(NumberConversionError::ValueTooLarge(number))
+    }
+
+    /// Convert a number using language-specific words
+    ///
+    /// The scale words from a million up are visited from largest to smallest, as
+    /// `convert` does in English. Every count stays below one million, Spanish counting
+    /// up to "Novecientos Noventa y Nueve Mil Millones" in the long scale, so counts
+    /// are spelled by `append_million_group` without recursing.
+    fn convert_with_lang(
+        number: i64,
+        words: &LanguageWords,
+    ) -> Result<Vec<String>, NumberConversionError> {
+        if number >= i64::MAX / 2 {
+            return Err(NumberConversionError::ValueTooLarge(number));
+        }
+
+        let mut result = Vec::new();
+        let mut remaining = number;
+        for scale in words.scales {
+            let count = remaining / scale.value;
+            if count == 0 {
+                continue;
+            }
+            remaining %= scale.value;
+            append_scale(count, scale, words, &mut result, append_million_group)?;
+        }
+
+        append_million_group(remaining, words, &mut result)?;
+        Ok(result)
+    }
+
+    /// Appends the words of a group below one million to `result`, joined to any
+    /// words already there
+    fn append_million_group(
+        number: i64,
+        words: &LanguageWords,
+        result: &mut Vec<String>,
+    ) -> Result<(), NumberConversionError> {
+        let thousands = number / 1000;
+        if thousands > 0 {
+            append_scale(
+                thousands,
+                &words.thousand,
+                words,
+                result,
+                append_small_group,
+            )?;
+        }
+
+        append_small_group(number % 1000, words, result)
+    }
+
+    /// Appends a scale word and its count to `result`, joined to any words already
+    /// there, spelling the count with `spell_count`
+    ///
+    /// A count of one is left out ("Mil", "مليون") except from a million up in
+    /// Spanish, which shortens "Uno" before the noun ("Un Millón"). A count of two is
+    /// left out before a dual ("مليونان").
+    fn append_scale(
+        count: i64,
+        scale: &ScaleWord,
+        words: &LanguageWords,
+        result: &mut Vec<String>,
+        spell_count: fn(i64, &LanguageWords, &mut Vec<String>) -> Result<(), NumberConversionError>,
+    ) -> Result<(), NumberConversionError> {
+        if words.and_placement == AndPlacement::EveryGroup && !result.is_empty() {
+            result.push(words.and.to_string());
+        }
+
+        match (count, scale.dual) {
+            (1, _) => {
+                if words.language == Language::Spanish && scale.value >= 1_000_000 {
+                    result.push("Un".to_string());
+                }
+                result.push(scale.singular.to_string());
+            }
+            (2, Some(dual)) => result.push(dual.to_string()),
+            _ => {
+                // Spelled on its own so no "و" goes between the "و" above and the count
+                let mut count_words = Vec::new();
+                spell_count(count, words, &mut count_words).map_err(|e| {
+                    NumberConversionError::ConversionError {
+                        context: format!("Failed to convert {} group", scale.singular),
+                        source: Box::new(e),
+                    }
+                })?;
+                result.extend(count_words);
+                result.push(scale_form(scale, count, words));
+            }
+        }
+        Ok(())
+    }
+
+    /// The form of a scale word following a count above one
+    ///
+    /// Arabic uses the dual alone for two (see `append_scale`) and otherwise agrees
+    /// with the last two digits of the count: the plural after 3-10 ("ثلاثة آلاف"),
+    /// the accusative singular after 11-99 ("أحد عشر ألفًا") and the singular after a
+    /// round hundred ("مائة ألف"). Other languages use the plural ("Dos Millones").
+    fn scale_form(scale: &ScaleWord, count: i64, words: &LanguageWords) -> String {
+        if words.language != Language::Arabic {
+            return scale.plural.to_string();
+        }
+        match count % 100 {
+            3..=10 => scale.plural.to_string(),
+            11..=99 => format!("{}\u{64b}ا", scale.singular),
+            _ => scale.singular.to_string(),
+        }
+    }
+
+    /// Appends the words of a number below one thousand to `result`, joined to any
+    /// words already there
+    fn append_small_group(
+        number: i64,
+        words: &LanguageWords,
+        result: &mut Vec<String>,
+    ) -> Result<(), NumberConversionError> {
+        let mut remaining = number;
+        // Arabic puts the units before the tens; where "و" or "y" goes comes from
+        // `words.and_placement`
+        let arabic = words.language == Language::Arabic;
+
+        // Handle hundreds
+        if remaining >= 100 {
+            let hundreds = remaining / 100;
+            remaining %= 100;
+
+            if words.and_placement == AndPlacement::EveryGroup && !result.is_empty() {
+                result.push(words.and.to_string());
+            }
+
+            if words.language == Language::Spanish && hundreds == 1 && remaining == 0 {
+                // "Cien" only for an exact hundred; "Ciento Uno" otherwise
+                result.push(words.hundred.to_string());
+            } else {
+                result.push(table_word(words.hundreds, hundreds, "hundreds")?.to_string());
+            }
+            if words.and_placement == AndPlacement::AfterHundreds && remaining > 0 {
+                result.push(words.and.to_string());
+            }
+        }
+
+        // Handle tens and units
+        if remaining > 0 {
+            // Spanish only puts "y" between the tens and the units ("Mil Doscientos
+            // Treinta y Cuatro")
+            if words.and_placement == AndPlacement::EveryGroup && !result.is_empty() {
+                result.push(words.and.to_string());
+            }
+
+            if remaining < 20 {
+                result.push(table_word(words.units, remaining, "units")?.to_string());
+            } else {
+                let tens_digit = remaining / 10;
+                let units_digit = remaining % 10;
+
+                // For Arabic, units come before tens
+                if arabic {
+                    if units_digit > 0 {
+                        result.push(table_word(words.units, units_digit, "units")?.to_string());
+                        if !words.and.is_empty() {
+                            result.push(words.and.to_string());
+                        }
+                    }
+                    result.push(table_word(words.tens, tens_digit, "tens")?.to_string());
+                } else {
+                    result.push(table_word(words.tens, tens_digit, "tens")?.to_string());
+                    if units_digit > 0 {
+                        if words.and_placement == AndPlacement::TensUnits {
+                            result.push(words.and.to_string());
+                        }
+                        result.push(table_word(words.units, units_digit, "units")?.to_string());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Test hook: converts with the English tables but the given `units`, bypassing
+    /// the table check in `get_language_words`
+    #[cfg(test)]
+    pub(crate) fn convert_with_units(
+        number: i64,
+        units: &'static [&'static str],
+    ) -> Result<Vec<String>, NumberConversionError> {
+        convert_with_lang(number, &LanguageWords { units, ..EN_WORDS })
+    }
+}
+
+/// JavaScript bindings for use from WebAssembly, enabled by the `wasm` feature
+///
+/// Errors are returned to JavaScript as their `Display` message.
+#[cfg(feature = "wasm")]
+pub mod wasm {
+    use super::*;
+    use wasm_bindgen::prelude::*;
+
+    fn to_js_error(error: NumberConversionError) -> JsValue {
+        JsValue::from_str(&error.to_string())
+    }
+
+    /// Converts a number to text in the given language ("en", "es", "ar")
+    #[wasm_bindgen(js_name = numberToText)]
+    pub fn number_to_text(number: i64, lang: &str) -> Result<String, JsValue> {
+        converter::number_to_text_lang(number, lang).map_err(to_js_error)
+    }
+
+    /// Converts a number to Roman numerals (1-3999)
+    #[wasm_bindgen(js_name = toRoman)]
+    pub fn to_roman(number: i64) -> Result<String, JsValue> {
+        converter::to_roman(number).map_err(to_js_error)
+    }
+
+    /// Converts a number to its ordinal form ("Twenty One (21st)")
+    #[wasm_bindgen(js_name = toOrdinal)]
+    pub fn to_ordinal(number: i64) -> Result<String, JsValue> {
+        converter::to_ordinal(number).map_err(to_js_error)
+    }
+
+    /// Formats an amount as US dollars and cents
+    #[wasm_bindgen(js_name = toCurrency)]
+    pub fn to_currency(amount: f64) -> Result<String, JsValue> {
+        converter::to_currency(amount).map_err(to_js_error)
+    }
+}
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub use converter::convert_for_bench;
+pub use converter::{
+    approximate_to_text, binary_repr_to_text, bytes_to_text, card_number_to_text, check_language,
+    clock_position_to_text, convert_with_scales, coordinate_to_text, date_to_text,
+    digit_count_to_text, digits_to_phonetic, format_grouped, fraction_to_decimal_text, from_roman,
+    grouping_separator, largest_scale, military_time_to_text, military_time_to_text_with, nth_word,
+    number_groups, number_groups_with, number_to_text, number_to_text_lang,
+    number_to_text_lang_segments, number_to_text_segments, number_to_text_with_options,
+    ordinal_suffix, pluralize, prime_factors_to_text, repeating_decimal_to_text, scale_table,
+    short_form_to_text, spell_csv_column, spell_list, spell_list_lang, spell_ordinal_list,
+    subtitle_lines, supported_languages, to_arabic_digits, to_digits_text, to_morse, to_ordinal,
+    to_ordinal_lang, to_ordinal_lang_with, to_ordinal_numeric, to_ordinal_words, to_roman,
+    to_roman_clock, to_roman_lower, to_text_with_groupings, trader_reading_to_text, AndStyle,
+    BidiMode, Casing, CheckOptions, CheckZero, ConversionOptions, Converter, Currency, Dialect,
+    DurationStyle, IndianLabels, Language, NegativeWord, OrdinalForm, ScaleSystem, SignPlacement,
+    SpelledNumber, ZeroWord,
+};
+#[cfg(feature = "std")]
+pub use converter::{
+    decimal_to_fraction_text, decimal_to_text, decimal_to_text_lang, decimal_to_text_strict,
+    duration_to_text, measurement_to_text, to_check_amount, to_currency, to_currency_lang,
+    to_currency_only, to_currency_with, to_currency_with_options, write_number_to,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_digits() {
+        assert_eq!(number_to_text(0).unwrap(), "Zero");
+        assert_eq!(number_to_text(1).unwrap(), "One");
+        assert_eq!(number_to_text(5).unwrap(), "Five");
+        assert_eq!(number_to_text(9).unwrap(), "Nine");
+    }
+
+    #[test]
+    fn test_teens() {
+        assert_eq!(number_to_text(10).unwrap(), "Ten");
+        assert_eq!(number_to_text(11).unwrap(), "Eleven");
+        assert_eq!(number_to_text(15).unwrap(), "Fifteen");
+        assert_eq!(number_to_text(19).unwrap(), "Nineteen");
+    }
+
+    #[test]
+    fn test_tens() {
+        assert_eq!(number_to_text(20).unwrap(), "Twenty");
+        assert_eq!(number_to_text(42).unwrap(), "Forty Two");
+        assert_eq!(number_to_text(70).unwrap(), "Seventy");
+        assert_eq!(number_to_text(99).unwrap(), "Ninety Nine");
+    }
+
+    #[test]
+    fn test_hundreds() {
+        assert_eq!(number_to_text(100).unwrap(), "One Hundred");
+        assert_eq!(number_to_text(101).unwrap(), "One Hundred and One");
+        assert_eq!(number_to_text(110).unwrap(), "One Hundred and Ten");
+        assert_eq!(number_to_text(999).unwrap(), "Nine Hundred and Ninety Nine");
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        assert_eq!(number_to_text(1000).unwrap(), "One Thousand");
+        assert_eq!(number_to_text(1_000_000).unwrap(), "One Million");
+        assert_eq!(
+            number_to_text(1_234_567).unwrap(),
+            "One Million Two Hundred and Thirty Four Thousand Five Hundred and Sixty Seven"
+        );
+    }
+
+    /// The recursive scale-group conversion that `convert` used before it became
+    /// iterative, with its own word lists so it shares no code with `number_to_text`
+    fn recursive_number_to_text(number: i64) -> String {
+        const UNITS: [&str; 20] = [
+            "Zero",
+            "One",
+            "Two",
+            "Three",
+            "Four",
+            "Five",
+            "Six",
+            "Seven",
+            "Eight",
+            "Nine",
+            "Ten",
+            "Eleven",
+            "Twelve",
+            "Thirteen",
+            "Fourteen",
+            "Fifteen",
+            "Sixteen",
+            "Seventeen",
+            "Eighteen",
+            "Nineteen",
+        ];
+        const TENS: [&str; 10] = [
+            "", "", "Twenty", "Thirty", "Forty", "Fifty", "Sixty", "Seventy", "Eighty", "Ninety",
+        ];
+
+        for &(divisor, unit) in SCALE_UNITS.iter() {
+            if number >= divisor {
+                let mut text = format!("{} {}", recursive_number_to_text(number / divisor), unit);
+                if number % divisor != 0 {
+                    text.push(' ');
+                    text.push_str(&recursive_number_to_text(number % divisor));
+                }
+                return text;
+            }
+        }
+
+        let mut words = Vec::new();
+        if number >= 100 {
+            words.extend([UNITS[(number / 100) as usize], "Hundred"]);
+        }
+        let rest = (number % 100) as usize;
+        if rest > 0 && number >= 100 {
+            words.push("and");
+        }
+        if rest >= 20 {
+            words.push(TENS[rest / 10]);
+            match rest % 10 {
+                0 => {}
+                unit => words.push(UNITS[unit]),
+            }
+        } else if rest > 0 || number == 0 {
+            words.push(UNITS[rest]);
+        }
+        words.join(" ")
+    }
+
+    fn assert_matches_recursive(numbers: impl Iterator<Item = i64>) {
+        for number in numbers {
+            assert_eq!(
+                number_to_text(number).unwrap(),
+                recursive_number_to_text(number),
+                "mismatch for {}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_iterative_matches_recursive() {
+        assert_matches_recursive(0..=100_000);
+        assert_matches_recursive((100_000..=10_000_000).step_by(97));
+        assert_matches_recursive((1..i64::MAX / 2).step_by(99_999_999_999_989));
+        assert_matches_recursive(
+            [
+                999_999_999_999,
+                1_000_000_000_001,
+                4_000_000_000_000_000_000,
+            ]
+            .into_iter(),
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "lang-es", feature = "lang-ar"))]
+    fn test_lang_million_groups() {
+        let cases = [
+            (1_000_000, "es", "Un Millón"),
+            (1_001_000, "es", "Un Millón Mil"),
+            (2_000_000, "es", "Dos Millones"),
+            (100_000_000, "es", "Cien Millones"),
+            (
+                345_006_007,
+                "es",
+                "Trescientos Cuarenta y Cinco Millones Seis Mil Siete",
+            ),
+            (
+                999_999_999,
+                "es",
+                "Novecientos Noventa y Nueve Millones Novecientos Noventa y Nueve Mil \
+                 Novecientos Noventa y Nueve",
+            ),
+            (1_000_000, "ar", "مليون"),
+            (5_000_005, "ar", "خمسة ملايين و خمسة"),
+            (12_000_300, "ar", "اثنا عشر مليونًا و ثلاثمائة"),
+            (300_000_000, "ar", "ثلاثمائة مليون"),
+            (400_000_001, "ar", "أربعمائة مليون و واحد"),
+        ];
+        for (number, lang, text) in cases {
+            assert_eq!(
+                number_to_text_lang(number, lang).unwrap(),
+                text,
+                "mismatch for {} in {}",
+                number,
+                lang
+            );
+        }
+    }
+
+    /// Exhaustive version of `test_iterative_matches_recursive`; slow in debug builds,
+    /// run with `cargo test -- --ignored`
+    #[test]
+    #[ignore]
+    fn test_iterative_matches_recursive_exhaustive() {
+        assert_matches_recursive(0..=10_000_000);
+    }
+
+    #[test]
+    fn test_negative_numbers() {
+        assert_eq!(number_to_text(-1).unwrap(), "Minus One");
+        assert_eq!(number_to_text(-42).unwrap(), "Minus Forty Two");
+        assert_eq!(
+            number_to_text(-1234).unwrap(),
+            "Minus One Thousand Two Hundred and Thirty Four"
+        );
+    }
+
+    #[test]
+    fn test_error_handling() {
+        assert!(matches!(
+            number_to_text(i64::MAX),
+            Err(NumberConversionError::ValueTooLarge(_))
+        ));
+
+        // Test conversion error handling
+        assert!(number_to_text(999_999_999_999_999_999).is_ok());
+    }
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(number_to_text_segments(0).unwrap(), vec!["Zero"]);
+        assert_eq!(
+            number_to_text_segments(-105_042).unwrap(),
+            vec![
+                "Minus",
+                "One Hundred",
+                "and",
+                "Five",
+                "Thousand",
+                "Forty",
+                "Two"
+            ]
+        );
+        assert_eq!(
+            number_to_text_segments(1_234_567).unwrap().join(" "),
+            number_to_text(1_234_567).unwrap()
+        );
+        assert!(number_to_text_lang_segments(1, "fr").is_err());
+    }
+
+    #[test]
+    fn test_sentence_scales_casing() {
+        let options = ConversionOptions {
+            casing: Casing::SentenceScales,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(105_000, &options).unwrap(),
+            "One hundred and five Thousand"
+        );
+        assert_eq!(
+            number_to_text_with_options(-2_000_021, &options).unwrap(),
+            "Minus two Million twenty one"
+        );
+        let hyphenated = ConversionOptions {
+            hyphenate: true,
+            ..options
+        };
+        assert_eq!(
+            number_to_text_with_options(21_000, &hyphenated).unwrap(),
+            "Twenty-one Thousand"
+        );
+    }
+
+    #[test]
+    fn test_and_style() {
+        let spell = |number, and_style| {
+            number_to_text_with_options(
+                number,
+                &ConversionOptions {
+                    and_style,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+        assert_eq!(spell(101, AndStyle::Hundreds), "One Hundred and One");
+        assert_eq!(spell(101, AndStyle::Omit), "One Hundred One");
+        assert_eq!(spell(101, AndStyle::British), "One Hundred and One");
+        assert_eq!(spell(1001, AndStyle::Hundreds), "One Thousand One");
+        assert_eq!(spell(1001, AndStyle::British), "One Thousand and One");
+        assert_eq!(spell(1101, AndStyle::Omit), "One Thousand One Hundred One");
+        assert_eq!(
+            number_to_text_lang(101, "en").unwrap(),
+            "One Hundred and One"
+        );
+    }
+
+    #[test]
+    fn test_scale_word_casing() {
+        let options = ConversionOptions {
+            casing: Casing::ScaleWords,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(105_000, &options).unwrap(),
+            "one Hundred and five Thousand"
+        );
+        assert_eq!(
+            number_to_text_with_options(-2_000_001, &options).unwrap(),
+            "minus two Million one"
+        );
+        assert_eq!(number_to_text_with_options(0, &options).unwrap(), "zero");
+    }
+
+    #[test]
+    fn test_negative_word() {
+        let options = ConversionOptions {
+            negative_word: NegativeWord::Negative,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(-42, &options).unwrap(),
+            "Negative Forty Two"
+        );
+        assert_eq!(
+            number_to_text_with_options(42, &options).unwrap(),
+            "Forty Two"
+        );
+        assert_eq!(
+            "NEGATIVE".parse::<NegativeWord>().unwrap(),
+            NegativeWord::Negative
+        );
+        assert!("hyphen".parse::<NegativeWord>().is_err());
+    }
+
+    #[test]
+    fn test_dash_negative_word() {
+        let options = ConversionOptions {
+            negative_word: "dash".parse().unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(-42, &options).unwrap(),
+            "Dash Forty Two"
+        );
+        assert_eq!(
+            number_to_text_with_options(42, &options).unwrap(),
+            "Forty Two"
+        );
+    }
+
+    #[test]
+    fn test_sign_placement() {
+        let prefix = ConversionOptions::default();
+        assert_eq!(
+            number_to_text_with_options(-42, &prefix).unwrap(),
+            "Minus Forty Two"
+        );
+
+        let suffix = ConversionOptions {
+            sign_placement: SignPlacement::Suffix,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(-42, &suffix).unwrap(),
+            "Forty Two Minus"
+        );
+        assert_eq!(
+            number_to_text_with_options(42, &suffix).unwrap(),
+            "Forty Two"
+        );
+
+        let negative_suffix = ConversionOptions {
+            negative_word: NegativeWord::Negative,
+            ..suffix
+        };
+        assert_eq!(
+            number_to_text_with_options(-42, &negative_suffix).unwrap(),
+            "Forty Two Negative"
+        );
+    }
+
+    #[test]
+    fn test_zero_word() {
+        let spell = |zero_word| {
+            let options = ConversionOptions {
+                zero_word,
+                ..Default::default()
+            };
+            number_to_text_with_options(0, &options).unwrap()
+        };
+
+        assert_eq!(spell(None), "Zero");
+        assert_eq!(spell(Some(ZeroWord::Zero)), "Zero");
+        assert_eq!(spell(Some(ZeroWord::Nought)), "Nought");
+        assert_eq!(spell(Some(ZeroWord::Nil)), "Nil");
+        assert_eq!(spell(Some(ZeroWord::Oh)), "Oh");
+
+        // Only a standalone zero is replaced
+        let options = ConversionOptions {
+            zero_word: Some(ZeroWord::Nil),
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(100, &options).unwrap(),
+            "One Hundred"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_number_to() {
+        let mut buffer = Vec::new();
+        write_number_to(&mut buffer, -1042).unwrap();
+        assert_eq!(buffer, b"Minus One Thousand Forty Two");
+
+        let mut buffer = Vec::new();
+        write_number_to(&mut buffer, 0).unwrap();
+        assert_eq!(buffer, b"Zero");
+
+        let mut buffer = Vec::new();
+        let err = write_number_to(&mut buffer, i64::MAX).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_format_grouped() {
+        assert_eq!(format_grouped(0, ','), "0");
+        assert_eq!(format_grouped(999, ','), "999");
+        assert_eq!(format_grouped(1000, ','), "1,000");
+        assert_eq!(format_grouped(1_234_567, ','), "1,234,567");
+        assert_eq!(format_grouped(-1_234_567, '.'), "-1.234.567");
+        assert_eq!(format_grouped(i64::MIN, ','), "-9,223,372,036,854,775,808");
+        assert_eq!(grouping_separator("en").unwrap(), ',');
+        assert!(grouping_separator("fr").is_err());
+    }
+
+    #[test]
+    fn test_repeating_decimals() {
+        assert_eq!(
+            repeating_decimal_to_text("0.333...").unwrap(),
+            "Zero point Three Repeating"
+        );
+        assert_eq!(
+            repeating_decimal_to_text("0.1666...").unwrap(),
+            "Zero point One Six Repeating"
+        );
+        assert_eq!(
+            repeating_decimal_to_text("-3.142857142857...").unwrap(),
+            "Minus Three point One Four Two Eight Five Seven Repeating"
+        );
+        assert!(repeating_decimal_to_text("0.333").is_err());
+        assert!(repeating_decimal_to_text("0.3a3...").is_err());
+        assert!(repeating_decimal_to_text(".3...").is_err());
+    }
+
+    #[test]
+    fn test_fraction_to_decimal() {
+        assert_eq!(
+            fraction_to_decimal_text(1, 3).unwrap(),
+            "Zero point Three Repeating"
+        );
+        assert_eq!(
+            fraction_to_decimal_text(1, 6).unwrap(),
+            "Zero point One Six Repeating"
+        );
+        assert_eq!(
+            fraction_to_decimal_text(22, 7).unwrap(),
+            "Three point One Four Two Eight Five Seven Repeating"
+        );
+        assert_eq!(
+            fraction_to_decimal_text(-1, 4).unwrap(),
+            "Minus Zero point Two Five"
+        );
+        assert_eq!(fraction_to_decimal_text(4, -2).unwrap(), "Minus Two");
+        assert_eq!(
+            fraction_to_decimal_text(1, 100).unwrap(),
+            "Zero point Zero One"
+        );
+        assert!(fraction_to_decimal_text(1, 0).is_err());
+        assert!(fraction_to_decimal_text(1, 999_999_937).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_source_chain() {
+        let error = NumberConversionError::ConversionError {
+            context: "Failed to convert Million group".to_string(),
+            source: Box::new(NumberConversionError::ValueTooLarge(1000)),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Conversion error: Failed to convert Million group"
+        );
+
+        let source = error.source().expect("conversion errors carry a source");
+        assert_eq!(source.to_string(), "Number 1000 is too large to convert");
+        assert!(source.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decimal_non_finite() {
+        for number in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(
+                decimal_to_text(number),
+                Err(NumberConversionError::DecimalError(_))
+            ));
+            assert!(matches!(
+                to_currency(number),
+                Err(NumberConversionError::DecimalError(_))
+            ));
+        }
+        assert!(matches!(
+            decimal_to_text(1e300),
+            Err(NumberConversionError::DecimalError(_))
+        ));
+        assert_eq!(
+            decimal_to_text(f64::NAN).unwrap_err().to_string(),
+            "Decimal error: NaN is not a finite number"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[allow(clippy::approx_constant)] // 3.14159 is a digit count here, not π
+    fn test_decimal_strict() {
+        assert!(matches!(
+            decimal_to_text_strict(3.14159, 2),
+            Err(NumberConversionError::DecimalError(_))
+        ));
+        assert_eq!(
+            decimal_to_text_strict(3.14159, 5).unwrap(),
+            "Three point One Four One Five Nine"
+        );
+        assert_eq!(
+            decimal_to_text_strict(100.05, 2).unwrap(),
+            "One Hundred point Zero Five"
+        );
+        assert_eq!(
+            decimal_to_text_strict(-0.5, 2).unwrap(),
+            "Minus Zero point Five"
+        );
+        assert_eq!(decimal_to_text_strict(7.0, 0).unwrap(), "Seven");
+        assert!(decimal_to_text_strict(7.5, 0).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decimal_to_fraction_text() {
+        assert_eq!(
+            decimal_to_fraction_text(3.5, "en").unwrap(),
+            "Three and a Half"
+        );
+        assert_eq!(
+            decimal_to_fraction_text(2.25, "en").unwrap(),
+            "Two and a Quarter"
+        );
+        assert_eq!(
+            decimal_to_fraction_text(4.75, "en").unwrap(),
+            "Four and Three Quarters"
+        );
+        assert_eq!(
+            decimal_to_fraction_text(-3.5, "en").unwrap(),
+            "Minus Three and a Half"
+        );
+        assert_eq!(
+            decimal_to_fraction_text(3.4, "en").unwrap(),
+            decimal_to_text_lang(3.4, "en").unwrap()
+        );
+        assert_eq!(
+            decimal_to_fraction_text(0.5, "en").unwrap(),
+            decimal_to_text_lang(0.5, "en").unwrap()
+        );
+        assert_eq!(decimal_to_fraction_text(7.0, "en").unwrap(), "Seven");
+    }
+
+    #[cfg(all(feature = "std", feature = "lang-es"))]
+    #[test]
+    fn test_decimal_to_fraction_text_es() {
+        assert_eq!(decimal_to_fraction_text(3.5, "es").unwrap(), "Tres y Medio");
+        assert_eq!(
+            decimal_to_fraction_text(2.25, "es").unwrap(),
+            "Dos y Cuarto"
+        );
+        assert_eq!(
+            decimal_to_fraction_text(4.75, "es").unwrap(),
+            "Cuatro y Tres Cuartos"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_currency_sign_placement() {
+        let suffix = ConversionOptions {
+            sign_placement: SignPlacement::Suffix,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_currency_with_options(-100.00, Currency::Usd, &suffix).unwrap(),
+            "One Hundred Dollars Minus"
+        );
+        let negative_suffix = ConversionOptions {
+            negative_word: NegativeWord::Negative,
+            ..suffix.clone()
+        };
+        assert_eq!(
+            to_currency_with_options(-100.5, Currency::Usd, &negative_suffix).unwrap(),
+            "One Hundred Dollars and Fifty Cents Negative"
+        );
+        assert_eq!(
+            to_currency_with_options(100.0, Currency::Usd, &suffix).unwrap(),
+            "One Hundred Dollars"
+        );
+        assert_eq!(
+            to_currency_with_options(-100.0, Currency::Usd, &ConversionOptions::default()).unwrap(),
+            to_currency(-100.0).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_measurement_to_text() {
+        assert_eq!(
+            measurement_to_text(1.0, "Meter", "Meters").unwrap(),
+            "One Meter"
+        );
+        assert_eq!(
+            measurement_to_text(1.5, "Meter", "Meters").unwrap(),
+            "One Point Five Meters"
+        );
+        assert_eq!(
+            measurement_to_text(2.0, "Meter", "Meters").unwrap(),
+            "Two Meters"
+        );
+        assert_eq!(
+            measurement_to_text(0.0, "Meter", "Meters").unwrap(),
+            "Zero Meters"
+        );
+        assert_eq!(
+            measurement_to_text(-1.0, "Foot", "Feet").unwrap(),
+            "Minus One Foot"
+        );
+        assert_eq!(
+            measurement_to_text(1.001, "Foot", "Feet").unwrap(),
+            "One Foot"
+        );
+        assert!(measurement_to_text(f64::NAN, "Meter", "Meters").is_err());
+    }
+
+    #[cfg(all(feature = "std", feature = "lang-ar"))]
+    #[test]
+    fn test_decimal_arabic_digits() {
+        assert_eq!(
+            decimal_to_text_lang(3.05, "ar").unwrap(),
+            "ثلاثة فاصلة صفر خمسة"
+        );
+        assert_eq!(decimal_to_text_lang(0.5, "ar").unwrap(), "صفر فاصلة خمسة");
+        let words = decimal_to_text_lang(3.05, "ar").unwrap();
+        let words = words.split(' ').collect::<Vec<_>>();
+        assert_eq!(words[1], "فاصلة");
+        assert!(words.iter().all(|word| word.chars().all(|c| !c.is_ascii())));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_currency_parentheses() {
+        assert_eq!(
+            to_currency(-1.50).unwrap(),
+            "Minus One Dollar and Fifty Cents"
+        );
+        let parentheses = ConversionOptions {
+            sign_placement: SignPlacement::Parentheses,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_currency_with_options(-1.50, Currency::Usd, &parentheses).unwrap(),
+            "(One Dollar and Fifty Cents)"
+        );
+        assert_eq!(
+            to_currency_with_options(1.50, Currency::Usd, &parentheses).unwrap(),
+            "One Dollar and Fifty Cents"
+        );
+        assert_eq!(
+            number_to_text_with_options(-42_000, &parentheses).unwrap(),
+            "(Forty Two Thousand)"
+        );
+        let sentence = ConversionOptions {
+            casing: Casing::SentenceScales,
+            ..parentheses
+        };
+        assert_eq!(
+            number_to_text_with_options(-42_000, &sentence).unwrap(),
+            "(Forty two Thousand)"
+        );
+        assert_eq!(
+            "parens".parse::<SignPlacement>().unwrap(),
+            SignPlacement::Parentheses
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decimal_overflow() {
+        for number in [1e19, -1e19, 4.7e18] {
+            assert!(matches!(
+                decimal_to_text(number),
+                Err(NumberConversionError::DecimalError(_))
+            ));
+            assert!(matches!(
+                to_currency(number),
+                Err(NumberConversionError::DecimalError(_))
+            ));
+        }
+        assert!(to_currency(4e18).is_ok());
+    }
+
+    #[cfg(all(feature = "std", feature = "lang-es", feature = "lang-ar"))]
+    #[test]
+    fn test_decimal_to_text_lang() {
+        assert_eq!(
+            decimal_to_text_lang(-3.5, "es").unwrap(),
+            "Menos Tres coma Cinco"
+        );
+        assert_eq!(
+            decimal_to_text_lang(-3.5, "ar").unwrap(),
+            "سالب ثلاثة فاصلة خمسة"
+        );
+        assert_eq!(
+            decimal_to_text_lang(-0.05, "es").unwrap(),
+            "Menos Cero coma Cero Cinco"
+        );
+        assert_eq!(decimal_to_text_lang(2.0, "es").unwrap(), "Dos");
+        assert_eq!(
+            decimal_to_text_lang(112.5, "en").unwrap(),
+            "One Hundred and Twelve point Fifty"
+        );
+        assert_eq!(
+            decimal_to_text_lang(42.42, "en").unwrap(),
+            decimal_to_text(42.42).unwrap()
+        );
+        assert_eq!(
+            decimal_to_text_lang(-0.25, "en").unwrap(),
+            decimal_to_text(-0.25).unwrap()
+        );
+        assert!(matches!(
+            decimal_to_text_lang(1.5, "fr"),
+            Err(NumberConversionError::UnsupportedLanguage(_))
+        ));
+    }
+
+    #[test]
+    fn test_dialect_presets() {
+        let british = Dialect::BritishEnglish.options();
+        let american = Dialect::AmericanEnglish.options();
+
+        assert_eq!(
+            number_to_text_with_options(1005, &british).unwrap(),
+            "one thousand and five"
+        );
+        assert_eq!(
+            number_to_text_with_options(1005, &american).unwrap(),
+            "one thousand five"
+        );
+        assert_eq!(
+            number_to_text_with_options(2_000_121, &british).unwrap(),
+            "two million one hundred and twenty-one"
+        );
+        assert_eq!(
+            number_to_text_with_options(2_000_121, &american).unwrap(),
+            "two million one hundred twenty-one"
+        );
+        assert_eq!(number_to_text_with_options(0, &british).unwrap(), "nought");
+
+        let title = ConversionOptions {
+            casing: Casing::Title,
+            ..british
+        };
+        assert_eq!(
+            number_to_text_with_options(-3042, &title).unwrap(),
+            "Minus Three Thousand and Forty-Two"
+        );
+    }
+
+    #[test]
+    fn test_prime_factors_to_text() {
+        assert_eq!(prime_factors_to_text(1).unwrap(), "One");
+        assert_eq!(prime_factors_to_text(7).unwrap(), "Seven");
+        assert_eq!(
+            prime_factors_to_text(12).unwrap(),
+            "Two times Two times Three"
+        );
+        assert_eq!(
+            prime_factors_to_text(100).unwrap(),
+            "Two times Two times Five times Five"
+        );
+        assert!(matches!(
+            prime_factors_to_text(0),
+            Err(NumberConversionError::InvalidInput(_))
+        ));
+        assert!(prime_factors_to_text(-12).is_err());
+    }
+
+    #[test]
+    fn test_coordinate_to_text() {
+        assert_eq!(
+            coordinate_to_text(40.7, -74.0).unwrap(),
+            "Forty Point Seven North, Seventy Four Point Zero West"
+        );
+        assert_eq!(
+            coordinate_to_text(-33.8, 151.2).unwrap(),
+            "Thirty Three Point Eight South, One Hundred and Fifty One Point Two East"
+        );
+        assert!(coordinate_to_text(91.0, 0.0).is_err());
+        assert!(coordinate_to_text(0.0, f64::NAN).is_err());
+    }
+
+    #[cfg(all(feature = "std", feature = "lang-ar"))]
+    #[test]
+    fn test_arabic_currency() {
+        assert_eq!(
+            to_currency_lang(2.45, Currency::Sar, "ar").unwrap(),
+            "ريالان و خمسة و أربعون هللة"
+        );
+        assert_eq!(
+            to_currency_lang(100.00, Currency::Aed, "ar").unwrap(),
+            "مائة درهم"
+        );
+        assert_eq!(
+            to_currency_lang(5.01, Currency::Aed, "ar").unwrap(),
+            "خمسة دراهم و فلس واحد"
+        );
+        assert_eq!(
+            to_currency_lang(2.45, Currency::Sar, "en").unwrap(),
+            "Two Riyals and Forty Five Halalas"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_currency_only() {
+        assert_eq!(
+            to_currency_only(100.00, Currency::Usd, "en").unwrap(),
+            "One Hundred Dollars Only"
+        );
+        assert_eq!(
+            to_currency_only(1.5, Currency::Usd, "en").unwrap(),
+            "One Dollar and Fifty Cents Only"
+        );
+        #[cfg(feature = "lang-ar")]
+        assert_eq!(
+            to_currency_only(100.00, Currency::Sar, "ar").unwrap(),
+            "مائة ريال فقط"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_check_amount() {
+        let no_dollars = CheckOptions {
+            zero_dollars: CheckZero::No,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_check_amount(0.50, Currency::Usd, &no_dollars).unwrap(),
+            "No Dollars and 50/100"
+        );
+        assert_eq!(
+            to_check_amount(0.50, Currency::Usd, &CheckOptions::default()).unwrap(),
+            "Zero Dollars and 50/100"
+        );
+
+        let no_cents = CheckOptions {
+            zero_cents: CheckZero::No,
+            ..no_dollars
+        };
+        assert_eq!(
+            to_check_amount(12.0, Currency::Usd, &no_cents).unwrap(),
+            "Twelve Dollars and No/100"
+        );
+        assert_eq!(
+            to_check_amount(1.0, Currency::Usd, &CheckOptions::default()).unwrap(),
+            "One Dollar and 00/100"
+        );
+        assert_eq!(
+            to_check_amount(1.004, Currency::Bhd, &CheckOptions::default()).unwrap(),
+            "One Dinar and 004/1000"
+        );
+        assert!(to_check_amount(-0.50, Currency::Usd, &no_dollars).is_err());
+        assert_eq!("NO".parse::<CheckZero>().unwrap(), CheckZero::No);
+    }
+
+    #[cfg(all(feature = "std", feature = "lang-es"))]
+    #[test]
+    fn test_spanish_currency() {
+        assert_eq!(
+            to_currency_lang(2.45, Currency::Usd, "es").unwrap(),
+            "Dos Dólares y Cuarenta y Cinco Centavos"
+        );
+        assert_eq!(
+            to_currency_lang(1.01, Currency::Eur, "es").unwrap(),
+            "Un Euro y Un Céntimo"
+        );
+        assert_eq!(
+            to_currency_lang(21.0, Currency::Gbp, "es").unwrap(),
+            "Veinte y Una Libras"
+        );
+        assert_eq!(
+            to_currency_lang(1_000_000.0, Currency::Usd, "es").unwrap(),
+            "Un Millón de Dólares"
+        );
+        assert_eq!(
+            to_currency_lang(-3.0, Currency::Aed, "es").unwrap(),
+            "Menos Tres Dírhams"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_euro_and_pound() {
+        assert_eq!(
+            to_currency_with(2.45, Currency::Eur).unwrap(),
+            "Two Euros and Forty Five Cents"
+        );
+        assert_eq!(
+            to_currency_with(1.01, Currency::Gbp).unwrap(),
+            "One Pound and One Penny"
+        );
+        assert_eq!(
+            to_currency_with(3.50, Currency::Gbp).unwrap(),
+            "Three Pounds and Fifty Pence"
+        );
+        #[cfg(feature = "lang-ar")]
+        assert_eq!(
+            to_currency_lang(2.45, Currency::Usd, "ar").unwrap(),
+            "دولاران و خمسة و أربعون سنت"
+        );
+    }
+
+    #[test]
+    fn test_indefinite_article() {
+        let options = ConversionOptions {
+            indefinite_article: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(1000, &options).unwrap(),
+            "A Thousand"
+        );
+        assert_eq!(
+            number_to_text_with_options(1_000_000, &options).unwrap(),
+            "A Million"
+        );
+        assert_eq!(
+            number_to_text_with_options(100, &options).unwrap(),
+            "A Hundred"
+        );
+        assert_eq!(
+            number_to_text_with_options(1001, &options).unwrap(),
+            "One Thousand One"
+        );
+        assert_eq!(
+            number_to_text_with_options(2000, &options).unwrap(),
+            "Two Thousand"
+        );
+    }
+
+    #[test]
+    fn test_to_text_with_groupings() {
+        assert_eq!(to_text_with_groupings(12).unwrap(), "One Dozen");
+        assert_eq!(to_text_with_groupings(24).unwrap(), "Two Dozen");
+        assert_eq!(to_text_with_groupings(20).unwrap(), "One Score");
+        assert_eq!(to_text_with_groupings(60).unwrap(), "Three Score");
+        assert_eq!(to_text_with_groupings(37).unwrap(), "Thirty Seven");
+        assert_eq!(
+            to_text_with_groupings(480).unwrap(),
+            "Four Hundred and Eighty"
+        );
+    }
+
+    #[test]
+    fn test_units_and_tens_tables() {
+        let units = [
+            "One",
+            "Two",
+            "Three",
+            "Four",
+            "Five",
+            "Six",
+            "Seven",
+            "Eight",
+            "Nine",
+            "Ten",
+            "Eleven",
+            "Twelve",
+            "Thirteen",
+            "Fourteen",
+            "Fifteen",
+            "Sixteen",
+            "Seventeen",
+            "Eighteen",
+            "Nineteen",
+        ];
+        let tens = [
+            "Twenty", "Thirty", "Forty", "Fifty", "Sixty", "Seventy", "Eighty", "Ninety",
+        ];
+
+        for (number, word) in (1..).zip(units) {
+            assert_eq!(number_to_text(number).unwrap(), word);
+        }
+        for (tens_digit, tens_word) in (2..).zip(tens) {
+            assert_eq!(number_to_text(tens_digit * 10).unwrap(), tens_word);
+            for (unit, unit_word) in (1..10).zip(units) {
+                assert_eq!(
+                    number_to_text(tens_digit * 10 + unit).unwrap(),
+                    format!("{} {}", tens_word, unit_word)
+                );
+            }
+        }
+        assert_eq!(
+            number_to_text_lang(115, "en").unwrap(),
+            "One Hundred and Fifteen"
+        );
+
+        // Every compiled-in table covers 0-999
+        for lang in ["en", "es", "ar"] {
+            if check_language(lang).is_ok() {
+                for number in 0..1000 {
+                    assert!(
+                        number_to_text_lang(number, lang).is_ok(),
+                        "{} {}",
+                        lang,
+                        number
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_units_table() {
+        static SHORT_UNITS: [&str; 10] = [
+            "", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine",
+        ];
+        assert_eq!(
+            converter::convert_with_units(42, &SHORT_UNITS).unwrap(),
+            ["Forty", "Two"]
+        );
+        let error = converter::convert_with_units(15, &SHORT_UNITS).unwrap_err();
+        assert!(matches!(
+            error,
+            NumberConversionError::ConversionError { .. }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Conversion error: Incomplete language table: no units word for 15"
+        );
+    }
+
+    #[test]
+    fn test_to_arabic_digits() {
+        assert_eq!(
+            to_arabic_digits(&format_grouped(1_234_567, ',')),
+            "١٬٢٣٤٬٥٦٧"
+        );
+        assert_eq!(to_arabic_digits("-3.5"), "-٣٫٥");
+    }
+
+    #[test]
+    fn test_bytes_to_text() {
+        assert_eq!(bytes_to_text(1024, true).unwrap(), "One Kibibyte");
+        assert_eq!(bytes_to_text(1_048_576, true).unwrap(), "One Mebibyte");
+        assert_eq!(bytes_to_text(1_000_000, false).unwrap(), "One Megabyte");
+        assert_eq!(
+            bytes_to_text(1_500_000_000, false).unwrap(),
+            "One Point Five Gigabytes"
+        );
+        assert_eq!(bytes_to_text(999_999, false).unwrap(), "One Megabyte");
+        assert_eq!(bytes_to_text(1, false).unwrap(), "One Byte");
+        assert_eq!(bytes_to_text(0, true).unwrap(), "Zero Bytes");
+        assert_eq!(bytes_to_text(u64::MAX, true).unwrap(), "Sixteen Exbibytes");
+    }
+
+    #[test]
+    fn test_spell_list() {
+        assert_eq!(spell_list(&[]).unwrap(), "");
+        assert_eq!(spell_list(&[1]).unwrap(), "One");
+        assert_eq!(spell_list(&[1, 2]).unwrap(), "One and Two");
+        assert_eq!(spell_list(&[1, 2, 3]).unwrap(), "One, Two and Three");
+        assert_eq!(
+            spell_list(&[42, 103]).unwrap(),
+            "Forty Two and One Hundred and Three"
+        );
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn test_spell_list_spanish() {
+        assert_eq!(
+            spell_list_lang(&[1, 2, 3], "es").unwrap(),
+            "Uno, Dos y Tres"
+        );
+    }
+
+    #[test]
+    fn test_spelled_number() {
+        assert_eq!(format!("{}", SpelledNumber::new(42).unwrap()), "Forty Two");
+        assert_eq!("42".parse::<SpelledNumber>().unwrap().value(), 42);
+        assert!("4x2".parse::<SpelledNumber>().is_err());
+        assert!(matches!(
+            SpelledNumber::new(i64::MAX),
+            Err(NumberConversionError::ValueTooLarge(_))
+        ));
+
+        let british = SpelledNumber::with_options(21, Dialect::BritishEnglish.options()).unwrap();
+        assert_eq!(british.to_string(), "twenty-one");
+        assert!(SpelledNumber::with_language(1, "fr").is_err());
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn test_spelled_number_spanish() {
+        let number = SpelledNumber::with_language(2, "ES").unwrap();
+        assert_eq!(number.language(), "es");
+        assert_eq!(number.to_string(), "Dos");
+    }
+
+    #[test]
+    fn test_approximate_to_text() {
+        assert_eq!(approximate_to_text(1_234_567).unwrap(), "About One Million");
+        assert_eq!(approximate_to_text(950).unwrap(), "About One Thousand");
+        assert_eq!(approximate_to_text(42).unwrap(), "About Forty");
+        assert_eq!(
+            approximate_to_text(-1_650).unwrap(),
+            "About Minus Two Thousand"
+        );
+        assert_eq!(approximate_to_text(7).unwrap(), "About Seven");
+    }
+
+    #[test]
+    fn test_military_time_to_text() {
+        assert_eq!(
+            military_time_to_text("0830").unwrap(),
+            "Zero Eight Thirty Hours"
+        );
+        assert_eq!(
+            military_time_to_text("1300").unwrap(),
+            "Thirteen Hundred Hours"
+        );
+        assert_eq!(military_time_to_text("0000").unwrap(), "Zero Hundred Hours");
+        assert_eq!(
+            military_time_to_text("0005").unwrap(),
+            "Zero Zero Zero Five Hours"
+        );
+        assert_eq!(
+            military_time_to_text_with("0800", ZeroWord::Oh).unwrap(),
+            "Oh Eight Hundred Hours"
+        );
+        for invalid in ["830", "2400", "1260", "08:30", "+830"] {
+            assert!(military_time_to_text(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_date_to_text() {
+        assert_eq!(
+            date_to_text(2024, 3, 15, "en").unwrap(),
+            "The Fifteenth of March, Two Thousand Twenty Four"
+        );
+        assert_eq!(
+            date_to_text(1999, 12, 31, "en").unwrap(),
+            "The Thirty First of December, One Thousand Nine Hundred and Ninety Nine"
+        );
+        assert_eq!(
+            date_to_text(2024, 2, 29, "en").unwrap(),
+            "The Twenty Ninth of February, Two Thousand Twenty Four"
+        );
+        assert!(date_to_text(2023, 2, 29, "en").is_err());
+        assert!(date_to_text(2024, 13, 1, "en").is_err());
+        assert!(date_to_text(2024, 4, 0, "en").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lang-es")]
+    fn test_date_to_text_spanish() {
+        assert_eq!(
+            date_to_text(2024, 3, 15, "es").unwrap(),
+            "Quince de Marzo de Dos Mil Veinte y Cuatro"
+        );
+        assert_eq!(
+            date_to_text(2024, 5, 1, "es").unwrap(),
+            "Primero de Mayo de Dos Mil Veinte y Cuatro"
+        );
+    }
+
+    #[test]
+    fn test_binary_repr_to_text() {
+        assert_eq!(binary_repr_to_text(10), "One Zero One Zero");
+        assert_eq!(binary_repr_to_text(255), "One One One One One One One One");
+        assert_eq!(binary_repr_to_text(0), "Zero");
+        assert_eq!(binary_repr_to_text(-2), "Minus One Zero");
+    }
+
+    #[test]
+    fn test_number_groups() {
+        assert_eq!(
+            number_groups(1_234_567),
+            [(1, "Million"), (234, "Thousand"), (567, "")]
+        );
+        assert_eq!(
+            number_groups(-1_000_005),
+            [(1, "Million"), (0, "Thousand"), (5, "")]
+        );
+        assert_eq!(number_groups(42), [(42, "")]);
+        assert_eq!(number_groups(i64::MIN)[0], (9, "Quintillion"));
+        assert_eq!(
+            number_groups_with(12_345_678, ScaleSystem::Indian),
+            [(1, "Crore"), (23, "Lakh"), (45, "Thousand"), (678, "")]
+        );
+        assert_eq!(
+            number_groups_with(1_000_000_000, ScaleSystem::Long)[0],
+            (1, "Milliard")
+        );
+    }
+
+    #[test]
+    fn test_short_form_to_text() {
+        assert_eq!(short_form_to_text(1500).unwrap(), "One Point Five K");
+        assert_eq!(
+            short_form_to_text(2_300_000).unwrap(),
+            "Two Point Three Million"
+        );
+        assert_eq!(
+            short_form_to_text(999).unwrap(),
+            "Nine Hundred and Ninety Nine"
+        );
+        assert_eq!(short_form_to_text(42_000).unwrap(), "Forty Two K");
+        assert_eq!(short_form_to_text(999_950).unwrap(), "One Million");
+        assert_eq!(short_form_to_text(-1500).unwrap(), "Minus One Point Five K");
+        assert!(matches!(
+            short_form_to_text(i64::MAX),
+            Err(NumberConversionError::ValueTooLarge(i64::MAX))
+        ));
+    }
+
+    #[test]
+    fn test_converter_shared_across_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        fn assert_send_sync<T: Send + Sync + Clone>() {}
+        assert_send_sync::<Converter>();
+
+        let converter = Arc::new(
+            Converter::new("en")
+                .unwrap()
+                .with_options(ConversionOptions {
+                    casing: Casing::Lower,
+                    ..Default::default()
+                }),
+        );
+        let handles = (0..8)
+            .map(|offset| {
+                let converter = Arc::clone(&converter);
+                thread::spawn(move || {
+                    (0..1000)
+                        .map(|n| converter.convert(n * 8 + offset).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        for (offset, handle) in handles.into_iter().enumerate() {
+            let texts = handle.join().unwrap();
+            assert_eq!(texts.len(), 1000);
+            assert_eq!(
+                texts[5],
+                number_to_text(40 + offset as i64).unwrap().to_lowercase()
+            );
+        }
+        assert!(Converter::new("fr").is_err());
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn test_converter_spanish() {
+        let spanish = Converter::new("es-MX").unwrap();
+        assert_eq!(
+            spanish.clone().convert(21).unwrap(),
+            number_to_text_lang(21, "es").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_subtitle_lines() {
+        assert_eq!(
+            subtitle_lines(1_234_567, 40).unwrap(),
+            vec![
+                "One Million",
+                "Two Hundred and Thirty Four Thousand",
+                "Five Hundred and Sixty Seven",
+            ]
+        );
+
+        let number = -987_654_321_123_456_789;
+        let lines = subtitle_lines(number, 32).unwrap();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| line.len() <= 32), "{:?}", lines);
+        assert_eq!(lines.join(" "), number_to_text(number).unwrap());
+
+        assert_eq!(
+            subtitle_lines(2_000_005, 32).unwrap(),
+            vec!["Two Million Five"]
+        );
+        assert_eq!(subtitle_lines(0, 32).unwrap(), vec!["Zero"]);
+        assert!(subtitle_lines(42, 0).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_duration_to_text() {
+        use DurationStyle::{Fraction, Milliseconds};
+
+        assert_eq!(
+            duration_to_text(1.5, Milliseconds).unwrap(),
+            "One Second and Five Hundred Milliseconds"
+        );
+        assert_eq!(
+            duration_to_text(1.5, Fraction).unwrap(),
+            "One and a Half Seconds"
+        );
+        assert_eq!(
+            duration_to_text(0.25, Milliseconds).unwrap(),
+            "Two Hundred and Fifty Milliseconds"
+        );
+        assert_eq!(
+            duration_to_text(0.25, Fraction).unwrap(),
+            "A Quarter of a Second"
+        );
+        for style in [Milliseconds, Fraction] {
+            assert_eq!(
+                duration_to_text(90.001, style).unwrap(),
+                "One Minute, Thirty Seconds and One Millisecond"
+            );
+        }
+        assert_eq!(duration_to_text(3600.0, Milliseconds).unwrap(), "One Hour");
+        assert_eq!(duration_to_text(0.0, Fraction).unwrap(), "Zero Seconds");
+        assert!(duration_to_text(-1.0, Milliseconds).is_err());
+        assert!(matches!(
+            duration_to_text(f64::NAN, Milliseconds),
+            Err(NumberConversionError::DecimalError(_))
+        ));
+        assert_eq!("ms".parse::<DurationStyle>().unwrap(), Milliseconds);
+    }
+
+    #[test]
+    fn test_spell_csv_column() {
+        let csv = "item,qty,price\nbolts, 12 ,0.5\nnuts,n/a,1\nwashers\r\n";
+        assert_eq!(
+            spell_csv_column(csv, 1, "en").unwrap(),
+            "item,qty,price,\nbolts, 12 ,0.5,Twelve\nnuts,n/a,1,\nwashers,\r\n"
+        );
+        #[cfg(feature = "lang-es")]
+        assert_eq!(spell_csv_column("a,2", 1, "es").unwrap(), "a,2,Dos");
+        assert_eq!(spell_csv_column("", 0, "en").unwrap(), "");
+        assert!(spell_csv_column("1", 0, "fr").is_err());
+    }
+
+    #[test]
+    fn test_to_morse() {
+        assert_eq!(to_morse("123"), ".---- ..--- ...--");
+        assert_eq!(to_morse("-7"), "-....- --...");
+        assert_eq!(to_morse("1,000"), ".---- / ----- ----- -----");
+        assert_eq!(to_morse(""), "");
+    }
+
+    #[test]
+    fn test_supported_languages() {
+        let languages = supported_languages();
+        assert_eq!(languages[0], "en");
+        for &code in languages {
+            let language = Language::try_from(code).unwrap();
+            assert_eq!(language.code(), code);
+            assert!(number_to_text_lang(7, code).is_ok());
+        }
+        #[cfg(all(feature = "lang-es", feature = "lang-ar"))]
+        {
+            assert_eq!(languages, ["en", "es", "ar"]);
+            assert_eq!("es-MX".parse::<Language>().unwrap(), Language::Spanish);
+            assert_eq!(Language::try_from("Arabic").unwrap(), Language::Arabic);
+        }
+
+        assert!(matches!(
+            Language::try_from("fr"),
+            Err(NumberConversionError::UnsupportedLanguage(_))
+        ));
+        assert!(number_to_text_lang(7, "fr").is_err());
+    }
+
+    #[test]
+    fn test_bidi_mode() {
+        let spell = |bidi| {
+            number_to_text_with_options(
+                105,
+                &ConversionOptions {
+                    bidi,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+        assert_eq!(spell(BidiMode::Plain), "One Hundred and Five");
+        assert_eq!(
+            spell(BidiMode::Isolate),
+            "\u{2066}One Hundred and Five\u{2069}"
+        );
+        assert_eq!(spell(BidiMode::Reverse), "Five and Hundred One");
+        assert_eq!("ISOLATE".parse::<BidiMode>().unwrap(), BidiMode::Isolate);
+        assert!("rtl".parse::<BidiMode>().is_err());
+    }
+
+    #[test]
+    fn test_trader_reading_to_text() {
+        assert_eq!(
+            trader_reading_to_text(1_500_000).unwrap(),
+            "One and a Half Million"
+        );
+        assert_eq!(
+            trader_reading_to_text(2_250_000).unwrap(),
+            "Two and a Quarter Million"
+        );
+        assert_eq!(
+            trader_reading_to_text(3_750_000).unwrap(),
+            "Three and Three Quarters Million"
+        );
+        assert_eq!(trader_reading_to_text(4_000_000).unwrap(), "Four Million");
+        assert_eq!(
+            trader_reading_to_text(-2_500).unwrap(),
+            "Minus Two and a Half Thousand"
+        );
+        assert_eq!(
+            trader_reading_to_text(1_100_000).unwrap(),
+            "One Million One Hundred Thousand"
+        );
+        assert_eq!(
+            trader_reading_to_text(250).unwrap(),
+            "Two Hundred and Fifty"
+        );
+        assert!(matches!(
+            trader_reading_to_text(5_000_000_000_000_000_000),
+            Err(NumberConversionError::ValueTooLarge(
+                5_000_000_000_000_000_000
+            ))
+        ));
+        assert!(matches!(
+            trader_reading_to_text(i64::MIN),
+            Err(NumberConversionError::ValueTooLarge(i64::MIN))
+        ));
+    }
+
+    #[test]
+    fn test_scale_system() {
+        let long = ConversionOptions {
+            scale_system: ScaleSystem::Long,
+            ..Default::default()
+        };
+        assert_eq!(number_to_text(1_000_000_000).unwrap(), "One Billion");
+        assert_eq!(
+            number_to_text_with_options(1_000_000_000, &long).unwrap(),
+            "One Milliard"
+        );
+        assert_eq!(number_to_text(1_000_000_000_000).unwrap(), "One Trillion");
+        assert_eq!(
+            number_to_text_with_options(1_000_000_000_000, &long).unwrap(),
+            "One Billion"
+        );
+        assert_eq!(
+            number_to_text_with_options(2_500_000_000_000_000, &long).unwrap(),
+            "Two Billiard Five Hundred Billion"
+        );
+        assert_eq!("long".parse::<ScaleSystem>().unwrap(), ScaleSystem::Long);
+        assert!("metric".parse::<ScaleSystem>().is_err());
+    }
+
+    #[test]
+    fn test_card_number_to_text() {
+        assert_eq!(
+            card_number_to_text("4242 4242 4242 4242").unwrap(),
+            "Four Two Four Two Four Two Four Two Four Two Four Two Four Two Four Two (valid)"
+        );
+        assert_eq!(
+            card_number_to_text("79927398713").unwrap(),
+            "Seven Nine Nine Two Seven Three Nine Eight Seven One Three (valid)"
+        );
+        assert_eq!(
+            card_number_to_text("79927398710").unwrap(),
+            "Seven Nine Nine Two Seven Three Nine Eight Seven One Zero (invalid)"
+        );
+        assert!(card_number_to_text("").is_err());
+        assert!(card_number_to_text("4242-42x2").is_err());
+    }
+
+    #[test]
+    fn test_convert_with_scales() {
+        let indian = [(10_000_000, "Crore"), (100_000, "Lakh"), (1000, "Thousand")];
+        assert_eq!(
+            convert_with_scales(12_000_000, &indian).unwrap(),
+            "One Crore Twenty Lakh"
+        );
+        assert_eq!(
+            convert_with_scales(1_234_567, &indian).unwrap(),
+            "Twelve Lakh Thirty Four Thousand Five Hundred and Sixty Seven"
+        );
+        assert_eq!(convert_with_scales(-5, &indian).unwrap(), "Minus Five");
+        assert_eq!(
+            convert_with_scales(1_234_567, scale_table()).unwrap(),
+            number_to_text(1_234_567).unwrap()
+        );
+        assert!(matches!(
+            convert_with_scales(10_000_000_000, &indian),
+            Err(NumberConversionError::ValueTooLarge(_))
+        ));
+        assert!(convert_with_scales(5, &[(1000, "Thousand"), (100_000, "Lakh")]).is_err());
+        assert!(convert_with_scales(5, &[]).is_err());
+        assert!(convert_with_scales(i64::MIN, scale_table()).is_err());
+    }
+
+    #[test]
+    fn test_clock_position_to_text() {
+        assert_eq!(clock_position_to_text(3).unwrap(), "Three O'Clock (III)");
+        assert_eq!(clock_position_to_text(12).unwrap(), "Twelve O'Clock (XII)");
+        assert_eq!(clock_position_to_text(4).unwrap(), "Four O'Clock (IIII)");
+        assert!(clock_position_to_text(13).is_err());
+        assert!(clock_position_to_text(0).is_err());
+    }
+
+    #[test]
+    fn test_indian_scale_system() {
+        let indian = ConversionOptions {
+            scale_system: ScaleSystem::Indian,
+            ..Default::default()
+        };
+        let spell = |number| number_to_text_with_options(number, &indian).unwrap();
+        assert_eq!(spell(100_000), "One Lakh");
+        assert_eq!(spell(10_000_000), "One Crore");
+        assert_eq!(
+            spell(1_234_567),
+            "Twelve Lakh Thirty Four Thousand Five Hundred and Sixty Seven"
+        );
+        assert_eq!(spell(1_000_000_000_000), "One Lakh Crore");
+        assert_eq!(
+            "indian".parse::<ScaleSystem>().unwrap(),
+            ScaleSystem::Indian
+        );
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("Dollar", 2, "en").unwrap(), "Dollars");
+        assert_eq!(pluralize("Dollar", 1, "en").unwrap(), "Dollar");
+        assert_eq!(pluralize("Dollar", -1, "en").unwrap(), "Dollar");
+        assert_eq!(pluralize("Penny", 5, "en").unwrap(), "Pence");
+        assert_eq!(pluralize("Penny", 1, "en").unwrap(), "Penny");
+        assert_eq!(pluralize("Fils", 3, "en").unwrap(), "Fils");
+        #[cfg(feature = "lang-es")]
+        {
+            assert_eq!(pluralize("Millón", 2, "es").unwrap(), "Millones");
+            assert_eq!(pluralize("Millón", 1, "es").unwrap(), "Millón");
+            assert_eq!(pluralize("Mil", 3, "es").unwrap(), "Mil");
+            assert_eq!(pluralize("Dólar", 2, "es").unwrap(), "Dólares");
+            assert_eq!(pluralize("Peso", 2, "es").unwrap(), "Pesos");
+        }
+    }
+
+    #[test]
+    fn test_indian_labels() {
+        let spell = |number, indian_labels| {
+            let options = ConversionOptions {
+                scale_system: ScaleSystem::Indian,
+                indian_labels,
+                ..Default::default()
+            };
+            number_to_text_with_options(number, &options).unwrap()
+        };
+        assert_eq!(spell(100_000, IndianLabels::Words), "One Lakh");
+        assert_eq!(spell(100_000, IndianLabels::Abbreviated), "One L");
+        assert_eq!(spell(100_000, IndianLabels::Plural), "One Lakh");
+        assert_eq!(
+            spell(25_000_000, IndianLabels::Plural),
+            "Two Crores Fifty Lakhs"
+        );
+        assert_eq!(
+            spell(25_000_000, IndianLabels::Abbreviated),
+            "Two Cr Fifty L"
+        );
+        assert_eq!(spell(2_100_000, IndianLabels::Plural), "Twenty One Lakhs");
+        assert_eq!(
+            spell(210_000_000, IndianLabels::Plural),
+            "Twenty One Crores"
+        );
+        assert_eq!(
+            spell(100_100_000, IndianLabels::Plural),
+            "Ten Crores One Lakh"
+        );
+        assert_eq!(
+            spell(1_000_000_000_000, IndianLabels::Plural),
+            "One Lakh Crores"
+        );
+        assert_eq!(
+            "short".parse::<IndianLabels>().unwrap(),
+            IndianLabels::Abbreviated
+        );
+    }
+
+    #[test]
+    fn test_locale_fallback() {
+        assert_eq!(number_to_text_lang(42, "en-GB").unwrap(), "Forty Two");
+        #[cfg(feature = "lang-es")]
+        {
+            assert_eq!(number_to_text_lang(42, "es-MX").unwrap(), "Cuarenta y Dos");
+            assert_eq!(number_to_text_lang(42, "es_AR").unwrap(), "Cuarenta y Dos");
+        }
+        assert!(matches!(
+            number_to_text_lang(42, "zz"),
+            Err(NumberConversionError::UnsupportedLanguage(lang)) if lang == "zz"
+        ));
+        assert!(number_to_text_lang(42, "zz-ES").is_err());
+    }
+
+    #[test]
+    fn test_digits_to_phonetic() {
+        assert_eq!(digits_to_phonetic("1A2B").unwrap(), "One Alpha Two Bravo");
+        assert_eq!(digits_to_phonetic("007").unwrap(), "Zero Zero Seven");
+        assert_eq!(digits_to_phonetic("x-9").unwrap(), "X-ray Nine");
+        assert!(digits_to_phonetic("").is_err());
+        assert!(digits_to_phonetic("A#1").is_err());
+    }
+
+    #[test]
+    fn test_exact_powers() {
+        assert_eq!(number_to_text(100_000).unwrap(), "One Hundred Thousand");
+        assert_eq!(
+            number_to_text(1_100_000).unwrap(),
+            "One Million One Hundred Thousand"
+        );
+        assert_eq!(
+            number_to_text(999_999).unwrap(),
+            "Nine Hundred and Ninety Nine Thousand Nine Hundred and Ninety Nine"
+        );
+        assert_eq!(
+            number_to_text(100_000_000_000).unwrap(),
+            "One Hundred Billion"
+        );
+        // Crore is the top Indian scale, so larger quotients are split again into lakh
+        let indian = ConversionOptions {
+            scale_system: ScaleSystem::Indian,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_text_with_options(1_000_000_000_000, &indian).unwrap(),
+            "One Lakh Crore"
+        );
+    }
+
+    #[test]
+    fn test_multi_group_quotients() {
+        assert_eq!(number_to_text(1_000_000_000).unwrap(), "One Billion");
+        assert_eq!(
+            number_to_text(12_345_000).unwrap(),
+            "Twelve Million Three Hundred and Forty Five Thousand"
+        );
+        let indian = ConversionOptions {
+            scale_system: ScaleSystem::Indian,
+            ..Default::default()
+        };
+        for number in [1_000_000_000, 12_345_000, 123_456_789_000_000] {
+            for options in [&ConversionOptions::default(), &indian] {
+                let result = number_to_text_with_options(number, options);
+                assert!(
+                    !matches!(result, Err(NumberConversionError::InvalidInput(_))),
+                    "{}: {:?}",
+                    number,
+                    result
+                );
+            }
+        }
+        assert_eq!(
+            number_to_text_with_options(1_000_000_000, &indian).unwrap(),
+            "One Hundred Crore"
+        );
+    }
+
+    #[test]
+    fn test_i64_min() {
+        assert!(matches!(
+            number_to_text(i64::MIN),
+            Err(NumberConversionError::ValueTooLarge(i64::MIN))
+        ));
+        for lang in ["en", "es", "ar"] {
+            assert!(matches!(
+                number_to_text_lang(i64::MIN, lang),
+                Err(NumberConversionError::ValueTooLarge(_))
+                    | Err(NumberConversionError::UnsupportedLanguage(_))
+            ));
+        }
+        assert!(to_ordinal(i64::MIN).is_err());
+        assert!(short_form_to_text(i64::MIN).is_err());
+        assert!(trader_reading_to_text(i64::MIN).is_err());
+        assert!(spell_list(&[1, i64::MIN]).is_err());
+        assert!(SpelledNumber::new(i64::MIN).is_err());
+        #[cfg(feature = "std")]
+        {
+            let mut out = Vec::new();
+            assert!(write_number_to(&mut out, i64::MIN).is_err());
+            assert!(out.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_largest_scale() {
+        assert_eq!(largest_scale(999), None);
+        assert_eq!(largest_scale(1000), Some("Thousand"));
+        assert_eq!(largest_scale(1_234_567), Some("Million"));
+        assert_eq!(largest_scale(-1_000_000_000_000), Some("Trillion"));
+    }
+
+    #[test]
+    fn test_digit_count() {
+        assert_eq!(digit_count_to_text(0).unwrap(), "One");
+        assert_eq!(digit_count_to_text(42).unwrap(), "Two");
+        assert_eq!(digit_count_to_text(12345).unwrap(), "Five");
+        assert_eq!(digit_count_to_text(-7).unwrap(), "One");
+        assert_eq!(digit_count_to_text(i64::MIN).unwrap(), "Nineteen");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_decimal_numbers() {
+        assert_eq!(decimal_to_text(42.42).unwrap(), "Forty Two point Forty Two");
+        assert_eq!(decimal_to_text(100.05).unwrap(), "One Hundred point Five");
+        assert_eq!(decimal_to_text(-1.50).unwrap(), "Minus One point Fifty");
+        assert_eq!(decimal_to_text(0.99).unwrap(), "Zero point Ninety Nine");
+        assert_eq!(
+            decimal_to_text(-0.25).unwrap(),
+            "Minus Zero point Twenty Five"
+        );
+        assert_eq!(decimal_to_text(-0.001).unwrap(), "Zero");
+    }
+
+    #[test]
+    fn test_ordinal_numbers() {
+        assert_eq!(to_ordinal(1).unwrap(), "One (1st)");
+        assert_eq!(to_ordinal(2).unwrap(), "Two (2nd)");
+        assert_eq!(to_ordinal(3).unwrap(), "Three (3rd)");
+        assert_eq!(to_ordinal(4).unwrap(), "Four (4th)");
+        assert_eq!(to_ordinal(11).unwrap(), "Eleven (11th)");
+        assert_eq!(to_ordinal(21).unwrap(), "Twenty One (21st)");
+    }
+
+    #[test]
+    fn test_ordinal_suffix() {
+        for (number, suffix) in [
+            (1, "st"),
+            (2, "nd"),
+            (3, "rd"),
+            (4, "th"),
+            (11, "th"),
+            (12, "th"),
+            (13, "th"),
+            (111, "th"),
+            (112, "th"),
+            (121, "st"),
+            (-1, "st"),
+        ] {
+            assert_eq!(ordinal_suffix(number), suffix, "{}", number);
+        }
+        assert_eq!(to_ordinal_numeric(42), "42nd");
+        assert_eq!(to_ordinal_numeric(113), "113th");
+    }
+
+    #[test]
+    fn test_nth_word() {
+        assert_eq!(nth_word(1).unwrap(), "First");
+        assert_eq!(nth_word(2).unwrap(), "Second");
+        assert_eq!(nth_word(3).unwrap(), "Third");
+        assert_eq!(nth_word(11).unwrap(), "Eleventh");
+        assert_eq!(nth_word(21).unwrap(), "Twenty First");
+        assert_eq!(nth_word(100).unwrap(), "One Hundredth");
+        assert_eq!(nth_word(-1).unwrap(), "Minus First");
+        assert_eq!(
+            spell_ordinal_list(&[1, 2, 3]).unwrap(),
+            "First, Second and Third"
+        );
+    }
+
+    #[test]
+    fn test_ordinal_words() {
+        assert_eq!(to_ordinal_words(1).unwrap(), "First");
+        assert_eq!(to_ordinal_words(12).unwrap(), "Twelfth");
+        assert_eq!(to_ordinal_words(20).unwrap(), "Twentieth");
+        assert_eq!(to_ordinal_words(42).unwrap(), "Forty Second");
+        assert_eq!(to_ordinal_words(100).unwrap(), "One Hundredth");
+        assert_eq!(to_ordinal_words(101).unwrap(), "One Hundred and First");
+        assert_eq!(to_ordinal_words(1000).unwrap(), "One Thousandth");
+        assert_eq!(to_ordinal_words(1_000_000).unwrap(), "One Millionth");
+    }
+
+    #[test]
+    #[cfg(all(feature = "lang-es", feature = "lang-ar"))]
+    fn test_ordinal_lang() {
+        assert_eq!(to_ordinal_lang(1, "es").unwrap(), "Primero");
+        assert_eq!(to_ordinal_lang(2, "es").unwrap(), "Segundo");
+        assert_eq!(to_ordinal_lang(3, "es").unwrap(), "Tercero");
+        assert_eq!(to_ordinal_lang(20, "es").unwrap(), "Vigésimo");
+        assert_eq!(to_ordinal_lang(21, "es").unwrap(), "Vigésimo Primero");
+        assert_eq!(to_ordinal_lang(1, "ar").unwrap(), "الأول");
+        assert_eq!(to_ordinal_lang(2, "ar").unwrap(), "الثاني");
+        assert_eq!(to_ordinal_lang(3, "ar").unwrap(), "الثالث");
+        assert_eq!(to_ordinal_lang(21, "ar").unwrap(), "الحادي و العشرون");
+        assert_eq!(to_ordinal_lang(42, "ar").unwrap(), "الثاني و الأربعون");
+        assert_eq!(to_ordinal_lang(42, "en").unwrap(), "Forty Second");
+        assert!(to_ordinal_lang(0, "es").is_err());
+        assert!(to_ordinal_lang(100, "ar").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lang-es")]
+    fn test_ordinal_lang_forms() {
+        let spanish = |number, form| to_ordinal_lang_with(number, "es", form).unwrap();
+        assert_eq!(spanish(1, OrdinalForm::Short), "Primer");
+        assert_eq!(spanish(3, OrdinalForm::Short), "Tercer");
+        assert_eq!(spanish(13, OrdinalForm::Short), "Decimotercer");
+        assert_eq!(spanish(21, OrdinalForm::Short), "Vigésimo Primer");
+        assert_eq!(spanish(2, OrdinalForm::Short), "Segundo");
+        assert_eq!(spanish(1, OrdinalForm::Feminine), "Primera");
+        assert_eq!(spanish(3, OrdinalForm::Feminine), "Tercera");
+        assert_eq!(spanish(21, OrdinalForm::Feminine), "Vigésima Primera");
+        assert_eq!(spanish(1, OrdinalForm::Masculine), "Primero");
+        assert_eq!(
+            to_ordinal_lang_with(2, "en", OrdinalForm::Feminine).unwrap(),
+            "Second"
+        );
+        assert_eq!(
+            "Feminine".parse::<OrdinalForm>().unwrap(),
+            OrdinalForm::Feminine
+        );
+        assert!("plural".parse::<OrdinalForm>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_currency() {
+        assert_eq!(to_currency(1.0).unwrap(), "One Dollar");
+        assert_eq!(to_currency(1.01).unwrap(), "One Dollar and One Cent");
+        assert_eq!(
+            to_currency(2.45).unwrap(),
+            "Two Dollars and Forty Five Cents"
+        );
+        assert_eq!(to_currency(100.00).unwrap(), "One Hundred Dollars");
+        assert_eq!(
+            to_currency(1.234).unwrap(),
+            "One Dollar and Twenty Three Cents"
+        );
+        assert_eq!(
+            to_currency(-1.50).unwrap(),
+            "Minus One Dollar and Fifty Cents"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_currency_negative_cents() {
+        assert_eq!(
+            to_currency(-0.01).unwrap(),
+            "Minus Zero Dollars and One Cent"
+        );
+        assert_eq!(
+            to_currency(-0.99).unwrap(),
+            "Minus Zero Dollars and Ninety Nine Cents"
+        );
+        assert_eq!(to_currency(-0.00).unwrap(), "Zero Dollars");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_currency_three_minor_decimals() {
+        assert_eq!(
+            to_currency_with(1.234, Currency::Bhd).unwrap(),
+            "One Dinar and Two Hundred and Thirty Four Fils"
+        );
+        assert_eq!(
+            to_currency_with(1.004, Currency::Bhd).unwrap(),
+            "One Dinar and Four Fils"
+        );
+        assert_eq!(to_currency_with(2.0, Currency::Bhd).unwrap(), "Two Dinars");
+        assert_eq!(
+            to_currency_with(0.001, Currency::Bhd).unwrap(),
+            "Zero Dinars and One Fils"
+        );
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        assert_eq!(to_roman(1).unwrap(), "I");
+        assert_eq!(to_roman(4).unwrap(), "IV");
+        assert_eq!(to_roman(9).unwrap(), "IX");
+        assert_eq!(to_roman(49).unwrap(), "XLIX");
+        assert_eq!(to_roman(99).unwrap(), "XCIX");
+        assert_eq!(to_roman(499).unwrap(), "CDXCIX");
+        assert_eq!(to_roman(999).unwrap(), "CMXCIX");
+        assert_eq!(to_roman(3999).unwrap(), "MMMCMXCIX");
+        assert!(to_roman(0).is_err());
+        assert!(to_roman(-1).is_err());
+        assert!(to_roman(4000).is_err());
+    }
+
+    #[test]
+    fn test_to_digits_text() {
+        assert_eq!(to_digits_text("007", "en").unwrap(), "Zero Zero Seven");
+        assert_eq!(to_digits_text("0", "en").unwrap(), "Zero");
+        assert!(to_digits_text("", "en").is_err());
+        assert!(to_digits_text("12a", "en").is_err());
+    }
+
+    #[test]
+    fn test_roman_lower() {
+        assert_eq!(to_roman_lower(1).unwrap(), "i");
+        assert_eq!(to_roman_lower(4).unwrap(), "iv");
+        assert_eq!(to_roman_lower(40).unwrap(), "xl");
+        assert_eq!(to_roman_lower(1994).unwrap(), "mcmxciv");
+        assert_eq!(to_roman_lower(3999).unwrap(), "mmmcmxcix");
+        assert!(to_roman_lower(4000).is_err());
+    }
+
+    #[test]
+    fn test_roman_clock() {
+        assert_eq!(to_roman_clock(4).unwrap(), "IIII");
+        assert_eq!(to_roman_clock(9).unwrap(), "IX");
+        assert_eq!(to_roman_clock(12).unwrap(), "XII");
+        assert!(to_roman_clock(14).is_err());
+        assert_eq!(to_roman(4).unwrap(), "IV");
+        assert!(to_roman_clock(0).is_err());
+    }
+
+    #[test]
+    fn test_from_roman() {
+        assert_eq!(from_roman("I").unwrap(), 1);
+        assert_eq!(from_roman("IX").unwrap(), 9);
+        assert_eq!(from_roman("xlii").unwrap(), 42);
+        assert_eq!(from_roman("MCMXC").unwrap(), 1990);
+        assert_eq!(from_roman("MMMCMXCIX").unwrap(), 3999);
+        assert!(from_roman("").is_err());
+        assert!(from_roman("IIII").is_err());
+        assert!(from_roman("VX").is_err());
+        assert!(from_roman("MMMM").is_err());
+        assert!(from_roman("12").is_err());
+        assert_eq!(from_roman("MMMDCCCLXXXVIII").unwrap(), 3888);
+        assert!(matches!(
+            from_roman(&"M".repeat(10_000)),
+            Err(NumberConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            from_roman("MMMMM"),
+            Err(NumberConversionError::ValueTooLarge(4000))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "lang-es")]
+    fn test_spanish_numbers() {
+        assert_eq!(number_to_text_lang(0, "es").unwrap(), "Cero");
+        assert_eq!(number_to_text_lang(1, "es").unwrap(), "Uno");
+        assert_eq!(number_to_text_lang(21, "es").unwrap(), "Veinte y Uno");
+        assert_eq!(
+            number_to_text_lang(1234, "es").unwrap(),
+            "Mil Doscientos Treinta y Cuatro"
+        );
+        assert_eq!(number_to_text_lang(115, "es").unwrap(), "Ciento Quince");
+        assert_eq!(
+            number_to_text_lang(2021, "es").unwrap(),
+            "Dos Mil Veinte y Uno"
+        );
+        assert_eq!(
+            number_to_text_lang_segments(-21, "es").unwrap(),
+            vec!["Menos", "Veinte", "y", "Uno"]
+        );
+        assert_eq!(grouping_separator("es").unwrap(), '.');
+        assert!(number_to_text_lang(42, "fr").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lang-ar")]
+    fn test_arabic_numbers() {
+        assert_eq!(number_to_text_lang(0, "ar").unwrap(), "صفر");
+        assert_eq!(number_to_text_lang(1, "ar").unwrap(), "واحد");
+        assert_eq!(number_to_text_lang(11, "ar").unwrap(), "أحد عشر");
+        assert_eq!(number_to_text_lang(21, "ar").unwrap(), "واحد و عشرون");
+        assert_eq!(
+            number_to_text_lang(1234, "ar").unwrap(),
+            "ألف و مائتان و أربعة و ثلاثون"
+        );
+        assert!(number_to_text_lang(42, "fr").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lang-es")]
+    fn test_spanish_millions() {
+        assert_eq!(
+            number_to_text_lang(1_000_042, "es").unwrap(),
+            "Un Millón Cuarenta y Dos"
+        );
+        assert_eq!(
+            number_to_text_lang(1_000_100, "es").unwrap(),
+            "Un Millón Cien"
+        );
+        assert_eq!(
+            number_to_text_lang(2_000_000, "es").unwrap(),
+            "Dos Millones"
+        );
+        assert_eq!(
+            number_to_text_lang(1042, "es").unwrap(),
+            "Mil Cuarenta y Dos"
+        );
+        assert_eq!(
+            number_to_text_lang(1_000_000_000, "es").unwrap(),
+            "Mil Millones"
+        );
+        assert_eq!(
+            number_to_text_lang(1_000_000_000_000, "es").unwrap(),
+            "Un Billón"
+        );
+        assert_eq!(
+            number_to_text_lang(2_000_000_000_000, "es").unwrap(),
+            "Dos Billones"
+        );
+        assert_eq!(
+            number_to_text_lang(1_000_001_000_000, "es").unwrap(),
+            "Un Billón Un Millón"
+        );
+        assert_eq!(
+            number_to_text_lang(4_000_000_000_000_000_000, "es").unwrap(),
+            "Cuatro Trillones"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lang-es")]
+    fn test_spanish_hundreds() {
+        assert_eq!(number_to_text_lang(100, "es").unwrap(), "Cien");
+        assert_eq!(
+            number_to_text_lang_segments(101, "es").unwrap()[0],
+            "Ciento"
+        );
+        assert_eq!(number_to_text_lang(500, "es").unwrap(), "Quinientos");
+        assert_eq!(number_to_text_lang(700, "es").unwrap(), "Setecientos");
+        assert_eq!(number_to_text_lang(900, "es").unwrap(), "Novecientos");
+        assert_eq!(number_to_text_lang(100_000, "es").unwrap(), "Cien Mil");
+    }
+
+    #[test]
+    #[cfg(feature = "lang-ar")]
+    fn test_arabic_millions() {
+        assert_eq!(
+            number_to_text_lang(1_000_042, "ar").unwrap(),
+            "مليون و اثنان و أربعون"
+        );
+        assert_eq!(
+            number_to_text_lang(1_000_100, "ar").unwrap(),
+            "مليون و مائة"
+        );
+        assert_eq!(number_to_text_lang(2_000_000, "ar").unwrap(), "مليونان");
+        assert_eq!(number_to_text_lang(3_000_000, "ar").unwrap(), "ثلاثة ملايين");
+        assert_eq!(
+            number_to_text_lang(11_000_000, "ar").unwrap(),
+            "أحد عشر مليونًا"
+        );
+        assert_eq!(
+            number_to_text_lang(100_000_000, "ar").unwrap(),
+            "مائة مليون"
+        );
+        assert_eq!(
+            number_to_text_lang(103_000_000, "ar").unwrap(),
+            "مائة و ثلاثة ملايين"
+        );
+        assert_eq!(
+            number_to_text_lang(123_000, "ar").unwrap(),
+            "مائة و ثلاثة و عشرون ألفًا"
+        );
+        assert_eq!(number_to_text_lang(1_000_000_000, "ar").unwrap(), "مليار");
+        assert_eq!(
+            number_to_text_lang(1_000_000_000_000, "ar").unwrap(),
+            "تريليون"
+        );
+        assert_eq!(
+            number_to_text_lang(2_000_000_000_000, "ar").unwrap(),
+            "تريليونان"
+        );
+        assert_eq!(
+            number_to_text_lang(1_000_001_000_000, "ar").unwrap(),
+            "تريليون و مليون"
+        );
+        assert_eq!(
+            number_to_text_lang(5_000_000_000, "ar").unwrap(),
+            "خمسة مليارات"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "lang-es", feature = "lang-ar"))]
+    fn test_negative_numbers_lang() {
+        assert_eq!(
+            number_to_text_lang(-42, "es").unwrap(),
+            "Menos Cuarenta y Dos"
+        );
+        assert_eq!(number_to_text_lang(-1000, "es").unwrap(), "Menos Mil");
+        assert_eq!(
+            number_to_text_lang(-1_000_001, "es").unwrap(),
+            "Menos Un Millón Uno"
+        );
+        assert_eq!(
+            number_to_text_lang(-42, "ar").unwrap(),
+            "سالب اثنان و أربعون"
+        );
+        assert_eq!(number_to_text_lang(-2000, "ar").unwrap(), "سالب ألفان");
+        assert_eq!(
+            number_to_text_lang(-1_000_001, "ar").unwrap(),
+            "سالب مليون و واحد"
+        );
+        assert!(matches!(
+            number_to_text_lang(-(i64::MAX / 2), "es"),
+            Err(NumberConversionError::ValueTooLarge(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "lang-ar")]
+    fn test_arabic_thousands() {
+        assert_eq!(number_to_text_lang(1000, "ar").unwrap(), "ألف");
+        assert_eq!(number_to_text_lang(2000, "ar").unwrap(), "ألفان");
+        assert_eq!(number_to_text_lang(3000, "ar").unwrap(), "ثلاثة آلاف");
+        assert_eq!(number_to_text_lang(10_000, "ar").unwrap(), "عشرة آلاف");
+        assert_eq!(number_to_text_lang(11_000, "ar").unwrap(), "أحد عشر ألفًا");
+        assert_eq!(
+            number_to_text_lang(2105, "ar").unwrap(),
+            "ألفان و مائة و خمسة"
+        );
+        assert_eq!(number_to_text_lang(1_001_000, "ar").unwrap(), "مليون و ألف");
+    }
+
+    #[test]
+    #[cfg(feature = "lang-ar")]
+    fn test_arabic_hundreds() {
+        assert_eq!(number_to_text_lang(300, "ar").unwrap(), "ثلاثمائة");
+        assert_eq!(number_to_text_lang(500, "ar").unwrap(), "خمسمائة");
+        assert_eq!(number_to_text_lang(900, "ar").unwrap(), "تسعمائة");
+        assert_eq!(number_to_text_lang(805, "ar").unwrap(), "ثمانمائة و خمسة");
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "lang-es", feature = "lang-ar")))]
+    fn test_compiled_out_languages() {
+        #[cfg(not(feature = "lang-es"))]
+        assert!(matches!(
+            number_to_text_lang(42, "es"),
+            Err(NumberConversionError::UnsupportedLanguage(_))
+        ));
+        #[cfg(not(feature = "lang-ar"))]
+        assert!(matches!(
+            number_to_text_lang(42, "ar"),
+            Err(NumberConversionError::UnsupportedLanguage(_))
+        ));
+        assert_eq!(number_to_text_lang(0, "en").unwrap(), "Zero");
+    }
+}
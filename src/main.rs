@@ -1,21 +1,59 @@
-use clap::Parser;
-use std::error::Error;
-use std::fmt;
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, ValueEnum};
+use clap_complete::{generate, Shell};
+use serde::Serialize;
+use std::env;
+use std::fs::{File, OpenOptions};
 use std::io;
-use std::io::Write;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 use std::process;
 
+use number_to_text::{
+    bytes_to_text, check_language, decimal_to_text_lang, format_grouped, from_roman,
+    grouping_separator, number_groups_with, number_to_text_lang, number_to_text_with_options,
+    repeating_decimal_to_text, supported_languages, to_arabic_digits, to_currency_with_options,
+    to_digits_text, to_morse, to_ordinal, to_roman, to_roman_lower, to_text_with_groupings,
+    BidiMode, ConversionOptions, Currency, Dialect, IndianLabels, NegativeWord,
+    NumberConversionError, ScaleSystem, SignPlacement, ZeroWord,
+};
+
+/// Environment variable holding the language used when --language is not given
+const LANGUAGE_ENV: &str = "NUM2TEXT_LANG";
+
 /// A command-line tool to convert numbers to their textual representation
-#[derive(Parser, Debug, Default)]
+#[derive(Parser, Debug, Default, Clone)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// The number to convert
     #[arg(short, long)]
     number: Option<String>,
 
-    /// Enable interactive mode
-    #[arg(short, long)]
-    interactive: bool,
+    /// Further numbers to convert, one result per line
+    #[arg(value_name = "NUMBERS", allow_negative_numbers = true)]
+    numbers: Vec<String>,
+
+    /// Convert every integer in an inclusive range ("1-5" or "1..=5")
+    #[arg(long, value_name = "START-END", value_parser = parse_range, allow_hyphen_values = true)]
+    range: Option<RangeInclusive<i64>>,
+
+    /// Convert every line of a file, one number per line ("-" reads from stdin)
+    #[arg(short, long, value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// Write results to a file instead of stdout, replacing its contents; errors still
+    /// go to stderr
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// With --output, add to the end of the file instead of replacing it
+    #[arg(long, requires = "output")]
+    append: bool,
+
+    /// Enable interactive mode, optionally replaying the commands in a script file
+    #[arg(short, long, value_name = "SCRIPT", num_args = 0..=1)]
+    interactive: Option<Option<PathBuf>>,
 
     /// Convert to ordinal form (1st, 2nd, etc)
     #[arg(short, long)]
@@ -29,938 +67,1311 @@ struct Args {
     #[arg(short, long)]
     roman: bool,
 
-    /// Language for text output (en, es, ar)
+    /// Letter case of Roman numeral output
+    #[arg(long, value_enum, default_value_t)]
+    roman_case: RomanCase,
+
+    /// Read exact multiples of twenty as scores and of twelve as dozens
+    #[arg(long)]
+    dozens: bool,
+
+    /// Read the number as a storage size in bytes (KB, MB, GB, ...)
+    #[arg(long)]
+    bytes: bool,
+
+    /// With --bytes, use binary units (KiB, MiB, GiB, ...)
+    #[arg(long, requires = "bytes")]
+    binary: bool,
+
+    /// Parse the input as a Roman numeral before converting it
+    #[arg(long)]
+    from_roman: bool,
+
+    /// Read integers with leading zeros ("007") as numbers instead of digit by digit
+    #[arg(long)]
+    allow_leading_zeros: bool,
+
+    /// Word used for negative numbers in English output (minus, negative, dash)
+    #[arg(long, default_value = "minus")]
+    negative_word: NegativeWord,
+
+    /// Place the negative word before or after the number in English output, or wrap
+    /// negatives in parentheses (prefix, suffix, parentheses)
+    #[arg(long, default_value = "prefix")]
+    sign_placement: SignPlacement,
+
+    /// Word used for a standalone zero in English output (zero, nought, nil, oh)
+    #[arg(long)]
+    zero_word: Option<ZeroWord>,
+
+    /// Read exactly one hundred, thousand, million, ... as "A Hundred", "A Thousand"
+    #[arg(long)]
+    indefinite_article: bool,
+
+    /// Scale names used in English output (short, long, indian)
+    #[arg(long, default_value = "short")]
+    system: ScaleSystem,
+
+    /// With --system indian, write lakh and crore as words, plural words or
+    /// abbreviations (words, plural, short)
+    #[arg(long, default_value = "words")]
+    indian_labels: IndianLabels,
+
+    /// Prepare English output for right-to-left text: plain, isolate (Unicode isolate
+    /// marks) or reverse (reversed word order)
+    #[arg(long, default_value = "plain")]
+    bidi: BidiMode,
+
+    /// English spelling preset (american, british); other flags refine it
+    #[arg(long)]
+    dialect: Option<Dialect>,
+
+    /// Language for text output (en, es, ar); defaults to $NUM2TEXT_LANG, then en
     #[arg(short, long, default_value = "en")]
     language: String,
+
+    /// Follow the words with the numeral, grouped per the language's convention
+    #[arg(long)]
+    annotate: bool,
+
+    /// Write the annotated numeral with Eastern Arabic-Indic digits and Arabic grouping
+    #[arg(long, requires = "annotate")]
+    arabic_digits: bool,
+
+    /// Output format for conversion results
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// Print the group decomposition of each integer to stderr before converting it
+    /// (English output only)
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Print the scale words in use with their powers of ten and exit
+    #[arg(long)]
+    explain_scale: bool,
+
+    /// Print a completion script for the shell (bash, zsh, fish, powershell, elvish)
+    #[arg(long, value_name = "SHELL", hide = true)]
+    generate_completion: Option<Shell>,
 }
 
-/// Error types for number conversion
-#[derive(Debug)]
-pub enum NumberConversionError {
-    /// Input number is too large to convert (exceeds i64::MAX/2)
-    ValueTooLarge(i64),
-    /// Invalid input provided during conversion
-    InvalidInput(String),
-    /// Internal conversion error
-    ConversionError(String),
-    /// Error parsing decimal number
-    DecimalError(String),
-    /// Unsupported language
-    UnsupportedLanguage(String),
+/// Output formats supported by the command-line tool
+#[derive(ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Plain text, one result per line
+    #[default]
+    Text,
+    /// JSON objects (an array in batch mode)
+    Json,
+    /// The digits of the input in International Morse code, one result per line
+    Morse,
 }
 
-impl fmt::Display for NumberConversionError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            NumberConversionError::ValueTooLarge(val) => {
-                write!(f, "Number {} is too large to convert", val)
-            }
-            NumberConversionError::InvalidInput(msg) => {
-                write!(f, "Invalid input: {}", msg)
-            }
-            NumberConversionError::ConversionError(msg) => {
-                write!(f, "Conversion error: {}", msg)
-            }
-            NumberConversionError::DecimalError(msg) => {
-                write!(f, "Decimal error: {}", msg)
-            }
-            NumberConversionError::UnsupportedLanguage(lang) => {
-                write!(f, "Unsupported language: {}", lang)
-            }
-        }
-    }
+/// Letter case of Roman numerals printed with --roman
+#[derive(ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum RomanCase {
+    /// Capital letters (XLII)
+    #[default]
+    Upper,
+    /// Small letters, as in outline numbering (xlii)
+    Lower,
 }
 
-impl Error for NumberConversionError {}
+/// Parses the command line, taking the language from `env_language` (the value of
+/// `NUM2TEXT_LANG`) when --language is not given
+fn parse_args<I, T>(args: I, env_language: Option<String>) -> Result<Args, clap::Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    let matches = Args::command().try_get_matches_from(args)?;
+    let mut args = Args::from_arg_matches(&matches)?;
+    if matches.value_source("language") == Some(ValueSource::DefaultValue) {
+        args.language = language_from_env(env_language, &mut io::stderr());
+    }
+    Ok(args)
+}
 
-/// Represents numeric scale units used in number conversion
-const SCALE_UNITS: [(i64, &str); 6] = [
-    (1_000_000_000_000_000_000, "Sextillion"),
-    (1_000_000_000_000_000, "Quintillion"),
-    (1_000_000_000_000, "Quadrillion"),
-    (1_000_000_000, "Trillion"),
-    (1_000_000, "Billion"),
-    (1_000, "Million"),
-];
+/// Validates a language code taken from the environment, writing a warning to `err`
+/// and falling back to English when it is not supported
+fn language_from_env<W: Write>(value: Option<String>, err: &mut W) -> String {
+    let code = value.unwrap_or_default().trim().to_lowercase();
+    if code.is_empty() {
+        return "en".to_string();
+    }
+    match check_language(&code) {
+        Ok(()) => code,
+        Err(e) => {
+            let _ = writeln!(err, "Warning: {} in {}, using en", e, LANGUAGE_ENV);
+            "en".to_string()
+        }
+    }
+}
 
-/// Module containing core number conversion functionality
-mod converter {
-    use super::*;
+/// A single conversion result as emitted in JSON mode
+#[derive(Serialize, Debug)]
+struct ConversionRecord<'a> {
+    input: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
 
-    const ROMAN_NUMERALS: [(i64, &str); 13] = [
-        (1000, "M"),
-        (900, "CM"),
-        (500, "D"),
-        (400, "CD"),
-        (100, "C"),
-        (90, "XC"),
-        (50, "L"),
-        (40, "XL"),
-        (10, "X"),
-        (9, "IX"),
-        (5, "V"),
-        (4, "IV"),
-        (1, "I"),
-    ];
-
-    /// Convert a number to Roman numerals
-    pub fn to_roman(number: i64) -> Result<String, NumberConversionError> {
-        if number <= 0 {
-            return Err(NumberConversionError::InvalidInput(
-                "Roman numerals must be positive".to_string(),
-            ));
-        }
-        if number > 3999 {
-            return Err(NumberConversionError::InvalidInput(
-                "Roman numerals cannot exceed 3999".to_string(),
-            ));
+impl<'a> ConversionRecord<'a> {
+    fn new(input: &'a str, args: &'a Args, result: Result<String, NumberConversionError>) -> Self {
+        match result {
+            Ok(text) => ConversionRecord {
+                input,
+                language: Some(&args.language),
+                text: Some(text),
+                error: None,
+            },
+            Err(e) => ConversionRecord {
+                input,
+                language: None,
+                text: None,
+                error: Some(e.to_string()),
+            },
         }
+    }
+}
 
-        let mut result = String::new();
-        let mut remaining = number;
+fn main() {
+    let args = parse_args(env::args_os(), env::var(LANGUAGE_ENV).ok()).unwrap_or_else(|e| e.exit());
 
-        for &(value, numeral) in ROMAN_NUMERALS.iter() {
-            while remaining >= value {
-                result.push_str(numeral);
-                remaining -= value;
-            }
+    if let Some(shell) = args.generate_completion {
+        print_completion(shell, &mut io::stdout());
+        return;
+    }
+
+    if args.explain_scale {
+        if let Err(e) = print_scale_table(args.system, &mut io::stdout()) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
         }
+        return;
+    }
 
-        Ok(result)
-    }
-
-    /// Language-specific number words
-    struct LanguageWords {
-        units: &'static [&'static str],
-        tens: &'static [&'static str],
-        scales: &'static [(&'static str, &'static str)],
-        zero: &'static str,
-        minus: &'static str,
-        and: &'static str,
-    }
-
-    /// English language number words
-    const EN_WORDS: LanguageWords = LanguageWords {
-        units: &[
-            "",          // 0
-            "One",       // 1
-            "Two",       // 2
-            "Three",     // 3
-            "Four",      // 4
-            "Five",      // 5
-            "Six",       // 6
-            "Seven",     // 7
-            "Eight",     // 8
-            "Nine",      // 9
-            "Ten",       // 10
-            "Eleven",    // 11
-            "Twelve",    // 12
-            "Thirteen",  // 13
-            "Fourteen",  // 14
-            "Fifteen",   // 15
-            "Sixteen",   // 16
-            "Seventeen", // 17
-            "Eighteen",  // 18
-            "Nineteen",  // 19
-        ],
-        tens: &[
-            "",        // 0
-            "",        // 10 (handled in units)
-            "Twenty",  // 20
-            "Thirty",  // 30
-            "Forty",   // 40
-            "Fifty",   // 50
-            "Sixty",   // 60
-            "Seventy", // 70
-            "Eighty",  // 80
-            "Ninety",  // 90
-        ],
-        scales: &[
-            ("Billion", "Billion"),   // 10^9
-            ("Billion", "Billion"),   // 10^9
-            ("Million", "Million"),   // 10^6
-            ("Thousand", "Thousand"), // 10^3
-            ("Hundred", "Hundred"),   // 10^2
-        ],
-        zero: "Zero",
-        minus: "Minus",
-        and: "",
-    };
+    let inputs = args
+        .number
+        .iter()
+        .chain(&args.numbers)
+        .map(String::as_str)
+        .collect::<Vec<_>>();
 
-    /// Spanish language number words
-    const ES_WORDS: LanguageWords = LanguageWords {
-        units: &[
-            "",           // 0
-            "Uno",        // 1
-            "Dos",        // 2
-            "Tres",       // 3
-            "Cuatro",     // 4
-            "Cinco",      // 5
-            "Seis",       // 6
-            "Siete",      // 7
-            "Ocho",       // 8
-            "Nueve",      // 9
-            "Diez",       // 10
-            "Once",       // 11
-            "Doce",       // 12
-            "Trece",      // 13
-            "Catorce",    // 14
-            "Quince",     // 15
-            "Dieciséis",  // 16
-            "Diecisiete", // 17
-            "Dieciocho",  // 18
-            "Diecinueve", // 19
-        ],
-        tens: &[
-            "",          // 0
-            "",          // 10 (handled in units)
-            "Veinte",    // 20
-            "Treinta",   // 30
-            "Cuarenta",  // 40
-            "Cincuenta", // 50
-            "Sesenta",   // 60
-            "Setenta",   // 70
-            "Ochenta",   // 80
-            "Noventa",   // 90
-        ],
-        scales: &[
-            ("Billón", "Billones"),           // 10^9
-            ("Mil Millones", "Mil Millones"), // 10^9 (alternative)
-            ("Millón", "Millones"),           // 10^6
-            ("Mil", "Mil"),                   // 10^3
-            ("Cien", "Cientos"),              // 10^2
-        ],
-        zero: "Cero",
-        minus: "Menos",
-        and: "y",
-    };
+    let mut out = open_output(&args).unwrap_or_else(|e| {
+        let path = args.output.as_deref().unwrap_or(Path::new("-"));
+        eprintln!("Error: {}: {}", path.display(), e);
+        process::exit(1);
+    });
 
-    /// Arabic language number words (masculine form)
-    const AR_WORDS: LanguageWords = LanguageWords {
-        units: &[
-            "",           // 0
-            "واحد",       // 1
-            "اثنان",      // 2
-            "ثلاثة",      // 3
-            "أربعة",      // 4
-            "خمسة",       // 5
-            "ستة",        // 6
-            "سبعة",       // 7
-            "ثمانية",     // 8
-            "تسعة",       // 9
-            "عشرة",       // 10
-            "أحد عشر",    // 11
-            "اثنا عشر",   // 12
-            "ثلاثة عشر",  // 13
-            "أربعة عشر",  // 14
-            "خمسة عشر",   // 15
-            "ستة عشر",    // 16
-            "سبعة عشر",   // 17
-            "ثمانية عشر", // 18
-            "تسعة عشر",   // 19
-        ],
-        tens: &[
-            "",       // 0
-            "",       // 10 (handled in units)
-            "عشرون",  // 20
-            "ثلاثون", // 30
-            "أربعون", // 40
-            "خمسون",  // 50
-            "ستون",   // 60
-            "سبعون",  // 70
-            "ثمانون", // 80
-            "تسعون",  // 90
-        ],
-        scales: &[
-            ("مليار", "مليار"), // 10^9
-            ("مليار", "مليار"), // 10^9
-            ("مليون", "مليون"), // 10^6
-            ("ألف", "ألف"),     // 10^3
-            ("مائة", "مائة"),   // 10^2
-        ],
-        zero: "صفر",
-        minus: "سالب",
-        and: "و",
+    let succeeded = if let [number_str] = inputs[..] {
+        // Direct conversion mode
+        let result = process_input(number_str, &args);
+        let failed = result.is_err();
+        match format_result(number_str, &args, result) {
+            Ok(text) => writeln!(out, "{}", text).map(|()| !failed),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                Ok(false)
+            }
+        }
+    } else if !inputs.is_empty() {
+        // Several numbers given on the command line
+        run_inputs(&inputs, &args, &mut out, &mut io::stderr())
+    } else if let Some(ref range) = args.range {
+        // Range mode
+        run_range(range, &args, &mut out, &mut io::stderr())
+    } else if let Some(ref path) = args.file {
+        // Batch conversion mode
+        run_file_mode(path, &args, &mut out)
+            .map(|()| true)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    } else {
+        run_without_inputs(&args);
+        Ok(true)
     };
 
-    /// Supported languages for number conversion
-    #[derive(Debug, Default)]
-    enum Language {
-        #[default]
-        English,
-        Spanish,
-        Arabic,
-    }
-
-    impl From<&str> for Language {
-        fn from(lang: &str) -> Self {
-            match lang.to_lowercase().as_str() {
-                "es" | "esp" | "spanish" => Language::Spanish,
-                "ar" | "ara" | "arabic" => Language::Arabic,
-                _ => Language::English,
-            }
+    match succeeded.and_then(|succeeded| out.flush().map(|()| succeeded)) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
         }
     }
+}
 
-    impl From<Language> for &str {
-        fn from(lang: Language) -> Self {
-            match lang {
-                Language::English => "en",
-                Language::Spanish => "es",
-                Language::Arabic => "ar",
-            }
+/// Opens the destination for results: the --output file, truncated unless --append
+/// is set, or stdout
+fn open_output(args: &Args) -> io::Result<Box<dyn Write>> {
+    match args.output {
+        Some(ref path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(args.append)
+                .truncate(!args.append)
+                .open(path)?;
+            Ok(Box::new(BufWriter::new(file)))
         }
+        None => Ok(Box::new(io::stdout())),
     }
+}
 
-    /// Get the language-specific words based on the language code
-    fn get_language_words(lang: &str) -> Result<&'static LanguageWords, NumberConversionError> {
-        match lang.to_lowercase().as_str() {
-            "en" | "eng" | "english" => Ok(&EN_WORDS),
-            "es" | "esp" | "spanish" => Ok(&ES_WORDS),
-            "ar" | "ara" | "arabic" => Ok(&AR_WORDS),
-            _ => Err(NumberConversionError::UnsupportedLanguage(lang.to_string())),
+/// Starts interactive mode, or explains the usage when it was not requested
+fn run_without_inputs(args: &Args) {
+    if let Some(ref script) = args.interactive {
+        // Interactive mode
+        match script {
+            Some(path) => run_script_mode(path, args),
+            None => run_interactive_mode(args),
         }
+    } else {
+        // No arguments provided, show help
+        println!(
+            "Please provide a number using --number or use --interactive for interactive mode"
+        );
+        println!("Use --help for more information");
     }
+}
 
-    /// Converts a number to its textual representation in English.
-    ///
-    /// # Arguments
-    /// * `number` - The number to convert
-    ///
-    /// # Returns
-    /// * `Result<String, NumberConversionError>` - The textual representation or an error
-    ///
-    /// # Errors
-    /// Returns `NumberConversionError::ValueTooLarge` if the number is too large to convert.
-    /// Returns `NumberConversionError::InvalidInput` if the input is invalid.
-    ///
-    /// # Example
-    /// ```
-    /// let text = number_to_text(42)?;
-    /// assert_eq!(text, "Forty Two");
-    /// ```
-    pub fn number_to_text(number: i64) -> Result<String, NumberConversionError> {
-        if number == 0 {
-            return Ok("Zero".to_string());
-        }
+/// Writes the completion script for `shell` to `output`
+fn print_completion<W: Write>(shell: Shell, output: &mut W) {
+    let mut command = Args::command();
+    let name = command.get_name().to_string();
+    generate(shell, &mut command, name, output);
+}
 
-        let mut words = Vec::new();
+/// Whether `input` is an unsigned run of digits padded with leading zeros ("007"); a
+/// lone "0" is not
+fn has_leading_zeros(input: &str) -> bool {
+    input.len() > 1 && input.starts_with('0') && input.bytes().all(|b| b.is_ascii_digit())
+}
 
-        if number < 0 {
-            words.push("Minus".to_string());
-        }
+/// Writes the table of `system`, one scale word per line with its power of ten and
+/// grouped value
+fn print_scale_table<W: Write>(system: ScaleSystem, output: &mut W) -> io::Result<()> {
+    writeln!(output, "{:?} scale:", system)?;
+    for &(divisor, name) in system.table() {
+        writeln!(
+            output,
+            "  {:<12} 10^{:<3} {}",
+            name,
+            divisor.ilog10(),
+            format_grouped(divisor, ',')
+        )?;
+    }
+    Ok(())
+}
 
-        words.extend(convert(number.abs())?);
-        Ok(words.join(" "))
-    }
-
-    /// Converts a number into its constituent word parts.
-    ///
-    /// # Arguments
-    /// * `number` - The positive number to convert
-    ///
-    /// # Returns
-    /// * `Result<Vec<String>, NumberConversionError>` - Vector of word parts or an error
-    ///
-    /// # Errors
-    /// Returns `NumberConversionError::ValueTooLarge` if the number is too large to convert.
-    fn convert(number: i64) -> Result<Vec<String>, NumberConversionError> {
-        if number >= i64::MAX / 2 {
-            return Err(NumberConversionError::ValueTooLarge(number));
-        }
+fn process_input(input: &str, args: &Args) -> Result<String, NumberConversionError> {
+    // Morse renders the digits as written, thousands separators included
+    if args.format == OutputFormat::Morse {
+        input.replace(',', "").parse::<i64>().map_err(|_| {
+            NumberConversionError::InvalidInput(format!("Morse output needs an integer: {}", input))
+        })?;
+        return Ok(to_morse(input));
+    }
 
-        let mut words = Vec::new();
+    if args.from_roman {
+        return process_integer(from_roman(input)?, args);
+    }
 
-        // Handle large scale numbers first
-        for &(divisor, unit) in SCALE_UNITS.iter() {
-            if number >= divisor {
-                return convert_large_number(number, divisor, unit).map_err(|e| {
-                    NumberConversionError::ConversionError(format!(
-                        "Failed to convert large number: {}",
-                        e
-                    ))
-                });
-            }
-        }
+    // Repeating decimals are written with a trailing "..." (e.g. 0.333...)
+    if input.ends_with("...") {
+        return repeating_decimal_to_text(input);
+    }
 
-        // Handle remaining small numbers
-        words.extend(convert_small_number(number).map_err(|e| {
-            NumberConversionError::ConversionError(format!("Failed to convert small number: {}", e))
-        })?);
-        Ok(words)
-    }
-
-    /// Converts a large number using scale units (million, billion, etc.).
-    ///
-    /// # Arguments
-    /// * `number` - The number to convert
-    /// * `divisor` - The scale divisor (e.g., 1_000_000 for millions)
-    /// * `unit` - The scale unit name (e.g., "Million")
-    ///
-    /// # Returns
-    /// * `Result<Vec<String>, NumberConversionError>` - Vector of word parts or an error
-    ///
-    /// # Errors
-    /// Returns `NumberConversionError::ConversionError` if conversion of parts fails.
-    fn convert_large_number(
-        number: i64,
-        divisor: i64,
-        unit: &str,
-    ) -> Result<Vec<String>, NumberConversionError> {
-        let quotient = number / divisor;
-        let remainder = number % divisor;
-
-        let mut words = Vec::new();
-        if quotient != 0 {
-            words.extend(convert_small_number(quotient).map_err(|e| {
-                NumberConversionError::ConversionError(format!("Failed to convert quotient: {}", e))
-            })?);
-            words.push(unit.to_string());
-        }
+    // "007" is a code rather than a quantity, so the plain reading keeps its zeros by
+    // reading each digit
+    let keep_zeros = has_leading_zeros(input) && !args.allow_leading_zeros;
 
-        if remainder != 0 {
-            words.extend(convert(remainder).map_err(|e| {
-                NumberConversionError::ConversionError(format!(
-                    "Failed to convert remainder: {}",
-                    e
-                ))
-            })?);
+    // Try parsing as integer first
+    if let Ok(number) = input.parse::<i64>() {
+        if let Some(result) = integer_mode(number, args) {
+            return result;
         }
-
-        Ok(words)
-    }
-
-    /// Converts a number less than 1000 to words.
-    ///
-    /// # Arguments
-    /// * `number` - The number to convert (must be less than 1000)
-    ///
-    /// # Returns
-    /// * `Result<Vec<String>, NumberConversionError>` - Vector of word parts or an error
-    ///
-    /// # Errors
-    /// Returns `NumberConversionError::InvalidInput` if number >= 1000.
-    fn convert_small_number(number: i64) -> Result<Vec<String>, NumberConversionError> {
-        if number >= 1000 {
-            return Err(NumberConversionError::InvalidInput(format!(
-                "Number {} is too large for convert_small_number (must be < 1000)",
-                number
-            )));
+        if keep_zeros {
+            return to_digits_text(input, &args.language);
         }
+        return cardinal_text(number, args);
+    }
+    if keep_zeros {
+        return to_digits_text(input, &args.language);
+    }
 
-        let mut words = Vec::new();
-
-        if number >= 100 {
-            words.push(format!("{} Hundred", ordinalize((number / 100) as usize)));
+    // Try parsing as decimal
+    if let Ok(number) = input.parse::<f64>() {
+        if args.currency {
+            return to_currency_with_options(number, Currency::Usd, &conversion_options(args));
         }
+        return decimal_to_text_lang(number, &args.language);
+    }
 
-        let remainder = number % 100;
+    Err(NumberConversionError::InvalidInput(
+        "Invalid number format. Examples of valid formats:\n\
+         - Integer: 42\n\
+         - Decimal: 42.42\n\
+         - Currency: 42.00\n\
+         - Negative: -42"
+            .to_string(),
+    ))
+}
 
-        if remainder > 0 {
-            if !words.is_empty() {
-                words.push("and".to_string());
-            }
+/// Formats a conversion result according to `args.format`.
+///
+/// In text mode errors are passed through for the caller to report on stderr; in JSON
+/// mode both successes and failures become a structured record.
+fn format_result(
+    input: &str,
+    args: &Args,
+    result: Result<String, NumberConversionError>,
+) -> Result<String, NumberConversionError> {
+    match args.format {
+        OutputFormat::Text | OutputFormat::Morse => result,
+        OutputFormat::Json => Ok(to_json(&ConversionRecord::new(input, args, result))),
+    }
+}
 
-            if remainder < 20 {
-                words.push(ordinalize(remainder as usize));
-            } else {
-                words.push(convert_tens(remainder / 10));
-                if remainder % 10 > 0 {
-                    words.push(ordinalize((remainder % 10) as usize));
-                }
-            }
-        }
+fn to_json<T: Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value).expect("conversion records always serialize")
+}
 
-        Ok(words)
+fn process_integer(number: i64, args: &Args) -> Result<String, NumberConversionError> {
+    integer_mode(number, args).unwrap_or_else(|| cardinal_text(number, args))
+}
+
+/// Converts `number` in the mode selected by --roman, --ordinal, --dozens or --bytes,
+/// or returns `None` for the plain cardinal reading
+fn integer_mode(number: i64, args: &Args) -> Option<Result<String, NumberConversionError>> {
+    if args.roman {
+        return Some(match args.roman_case {
+            RomanCase::Upper => to_roman(number),
+            RomanCase::Lower => to_roman_lower(number),
+        });
+    }
+    if args.ordinal {
+        return Some(to_ordinal(number));
     }
+    if args.dozens {
+        return Some(to_text_with_groupings(number));
+    }
+    if args.bytes {
+        return Some(
+            u64::try_from(number)
+                .map_err(|_| {
+                    NumberConversionError::InvalidInput(format!(
+                        "Byte count cannot be negative: {}",
+                        number
+                    ))
+                })
+                .and_then(|bytes| bytes_to_text(bytes, args.binary)),
+        );
+    }
+    None
+}
 
-    fn convert_tens(number: i64) -> String {
-        match number {
-            2 => "Twenty".to_string(),
-            3 => "Thirty".to_string(),
-            4 => "Forty".to_string(),
-            5 => "Fifty".to_string(),
-            6 => "Sixty".to_string(),
-            7 => "Seventy".to_string(),
-            8 => "Eighty".to_string(),
-            9 => "Ninety".to_string(),
-            _ => String::new(),
-        }
+/// Converts `number` to words in the selected language and casing, with any
+/// requested annotation
+fn cardinal_text(number: i64, args: &Args) -> Result<String, NumberConversionError> {
+    // The scale system only shapes English; other languages group by their own scales
+    if args.verbose && args.language == "en" {
+        eprintln!("{}", describe_groups(number, args.system));
     }
 
-    fn ordinalize(num: usize) -> String {
-        match num {
-            1 => "One".to_string(),
-            2 => "Two".to_string(),
-            3 => "Three".to_string(),
-            4 => "Four".to_string(),
-            5 => "Five".to_string(),
-            6 => "Six".to_string(),
-            7 => "Seven".to_string(),
-            8 => "Eight".to_string(),
-            9 => "Nine".to_string(),
-            10 => "Ten".to_string(),
-            11 => "Eleven".to_string(),
-            12 => "Twelve".to_string(),
-            13 => "Thirteen".to_string(),
-            14 => "Fourteen".to_string(),
-            15 => "Fifteen".to_string(),
-            16 => "Sixteen".to_string(),
-            17 => "Seventeen".to_string(),
-            18 => "Eighteen".to_string(),
-            19 => "Nineteen".to_string(),
-            _ => unreachable!("ordinalize called with number > 19"),
-        }
+    let text = if args.language != "en" {
+        number_to_text_lang(number, &args.language)?
+    } else {
+        number_to_text_with_options(number, &conversion_options(args))?
+    };
+
+    if args.arabic_digits {
+        let numeral = to_arabic_digits(&format_grouped(number, ','));
+        return Ok(format!("{} ({})", text, numeral));
     }
+    if args.annotate {
+        let separator = grouping_separator(&args.language)?;
+        return Ok(format!("{} ({})", text, format_grouped(number, separator)));
+    }
+    Ok(text)
+}
 
-    /// Converts a decimal number to its textual representation
-    pub fn decimal_to_text(number: f64) -> Result<String, NumberConversionError> {
-        let integer_part = number.trunc() as i64;
-        let decimal_part = ((number.fract() * 100.0).abs().round()) as i64;
+/// Describes how a number is split into groups and scale words for `--verbose`
+/// ("groups: [1, 234, 567] scales: [Million, Thousand, -]"), using the scale words
+/// of `system`
+fn describe_groups(number: i64, system: ScaleSystem) -> String {
+    let groups = number_groups_with(number, system);
+    let values = groups
+        .iter()
+        .map(|(value, _)| value.to_string())
+        .collect::<Vec<_>>();
+    let scales = groups
+        .iter()
+        .map(|&(_, scale)| if scale.is_empty() { "-" } else { scale })
+        .collect::<Vec<_>>();
+    format!(
+        "groups: [{}] scales: [{}]",
+        values.join(", "),
+        scales.join(", ")
+    )
+}
 
-        let mut result = number_to_text(integer_part)?;
+/// Builds the English conversion options selected on the command line
+fn conversion_options(args: &Args) -> ConversionOptions {
+    let preset = args.dialect.map(Dialect::options).unwrap_or_default();
+    ConversionOptions {
+        negative_word: args.negative_word,
+        sign_placement: args.sign_placement,
+        scale_system: args.system,
+        indian_labels: args.indian_labels,
+        bidi: args.bidi,
+        zero_word: args.zero_word.or(preset.zero_word),
+        indefinite_article: args.indefinite_article,
+        ..preset
+    }
+}
 
-        if decimal_part > 0 {
-            result.push_str(" point ");
-            result.push_str(&number_to_text(decimal_part)?);
+fn run_interactive_mode(args: &Args) {
+    match run_interactive(io::stdin().lock(), &mut io::stdout(), args, false) {
+        // The reader went away (e.g. `| head`); there is nobody left to report to
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
         }
+        Ok(()) => {}
+    }
+}
 
-        Ok(result)
+/// Replays the interactive commands in `path`, echoing each one after its prompt
+fn run_script_mode(path: &Path, args: &Args) {
+    let result = std::fs::read_to_string(path).and_then(|script| {
+        // End with an explicit quit so scripts need not include one
+        let script = format!("{}\nquit\n", script.trim_end());
+        run_interactive(script.as_bytes(), &mut io::stdout(), args, true)
+    });
+    if let Err(e) = result {
+        eprintln!("Error: {}: {}", path.display(), e);
+        process::exit(1);
     }
+}
 
-    /// Converts a number to its ordinal form (1st, 2nd, 3rd, etc)
-    pub fn to_ordinal(number: i64) -> Result<String, NumberConversionError> {
-        let suffix = match (number % 10, number % 100) {
-            (1, 11) | (2, 12) | (3, 13) => "th",
-            (1, _) => "st",
-            (2, _) => "nd",
-            (3, _) => "rd",
-            _ => "th",
-        };
+/// Runs the interactive command loop, reading commands from `input` and writing the
+/// prompt, results and errors to `output`
+///
+/// The session starts from the flags in `args`, so `-l es` or `NUM2TEXT_LANG` picks
+/// the language until a `lang` command switches it.
+///
+/// With `echo` set each command is written after its prompt, so a replayed script
+/// reads like a typed session.
+fn run_interactive<R: BufRead, W: Write>(
+    mut input: R,
+    output: &mut W,
+    args: &Args,
+    echo: bool,
+) -> io::Result<()> {
+    writeln!(output, "Number to Text Converter")?;
+    writeln!(output, "Commands:")?;
+    writeln!(output, "  <number>     - Convert a number to text")?;
+    writeln!(output, "  o <number>   - Convert to ordinal form")?;
+    writeln!(output, "  c <number>   - Format as currency")?;
+    writeln!(
+        output,
+        "  r <number>   - Convert to Roman numerals (r 1990 -> MCMXC)"
+    )?;
+    writeln!(
+        output,
+        "  r <numeral>  - Convert a Roman numeral to digits (r MCMXC -> 1990)"
+    )?;
+    writeln!(output, "  n <numeral>  - Convert a Roman numeral to text")?;
+    writeln!(
+        output,
+        "  lang [code]  - Show or switch the language ({})",
+        supported_languages().join(", ")
+    )?;
+    writeln!(output, "  quit         - Exit the program")?;
+    writeln!(output)?;
+
+    let mut args = args.clone();
 
-        let words = number_to_text(number)?;
-        Ok(format!("{} ({}{})", words, number, suffix))
-    }
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
 
-    /// Formats a number as currency
-    pub fn to_currency(number: f64) -> Result<String, NumberConversionError> {
-        if !number.is_finite() {
-            return Err(NumberConversionError::InvalidInput(
-                "Currency must be a finite number".to_string(),
-            ));
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            // End of input (Ctrl-D): finish the prompt line and leave like `quit`
+            writeln!(output)?;
+            break;
         }
 
-        // Round to 2 decimal places
-        let rounded = (number * 100.0).round() / 100.0;
-        let integer_part = rounded.trunc() as i64;
-        let cents = ((rounded.fract() * 100.0).abs().round()) as i64;
+        let line = line.trim();
+        if echo {
+            writeln!(output, "{}", line)?;
+        }
+        if line.is_empty() {
+            continue;
+        }
 
-        let mut result = number_to_text(integer_part)?;
-        result.push_str(" Dollar");
-        if integer_part.abs() != 1 {
-            result.push('s');
+        if line.eq_ignore_ascii_case("quit") {
+            break;
         }
 
-        if cents > 0 {
-            result.push_str(" and ");
-            result.push_str(&number_to_text(cents)?);
-            result.push_str(" Cent");
-            if cents != 1 {
-                result.push('s');
+        if line == "lang" {
+            writeln!(output, "{}", args.language)?;
+            continue;
+        }
+        if let Some(("lang", code)) = line.split_once(' ') {
+            let code = code.trim().to_lowercase();
+            match check_language(&code) {
+                Ok(()) => {
+                    writeln!(output, "Language set to {}", code)?;
+                    args.language = code;
+                }
+                Err(e) => writeln!(output, "Error: {}", e)?,
             }
+            continue;
         }
 
-        Ok(result)
-    }
-
-    /// Converts a number to its textual representation in the specified language
-    pub fn number_to_text_lang(number: i64, lang: &str) -> Result<String, NumberConversionError> {
-        let words = get_language_words(lang)?;
-
-        if number == 0 {
-            return Ok(words.zero.to_string());
+        match process_line(line, &args) {
+            Ok(text) => writeln!(output, "{}", text)?,
+            Err(e) => writeln!(output, "Error: {}", e)?,
         }
+    }
 
-        let mut result = Vec::new();
+    Ok(())
+}
 
-        if number < 0 {
-            result.push(words.minus.to_string());
+/// Converts a single line of input, applying the interactive command prefixes
+/// (`o`, `c`, `r`, `n`) on top of the flags already set in `args`.
+///
+/// A command letter only counts when followed by a space, so "o 21" is an ordinal
+/// while "o21" is passed through as-is. `r` works both ways: a numeral made only of
+/// letters ("r MCMXC") is read back to its digits, anything else is converted to
+/// Roman numerals.
+fn process_line(line: &str, args: &Args) -> Result<String, NumberConversionError> {
+    if let Some(("r", rest)) = line.split_once(' ') {
+        let numeral = rest.trim();
+        if !numeral.is_empty() && numeral.chars().all(|c| c.is_ascii_alphabetic()) {
+            return from_roman(numeral).map(|value| value.to_string());
         }
-
-        result.extend(convert_with_lang(number.abs(), words)?);
-        Ok(result.join(" "))
     }
 
-    /// Convert a number using language-specific words
-    fn convert_with_lang(
-        number: i64,
-        words: &LanguageWords,
-    ) -> Result<Vec<String>, NumberConversionError> {
-        if number >= i64::MAX / 2 {
-            return Err(NumberConversionError::ValueTooLarge(number));
-        }
+    let (command, number) = match line.split_once(' ') {
+        Some(("o", rest)) => ("ordinal", rest.trim()),
+        Some(("c", rest)) => ("currency", rest.trim()),
+        Some(("r", rest)) => ("roman", rest.trim()),
+        Some(("n", rest)) => ("from_roman", rest.trim()),
+        _ => ("text", line),
+    };
 
-        let mut result = Vec::new();
-        let mut remaining = number;
+    let mut args = args.clone();
+    args.number = Some(number.to_string());
+    args.ordinal |= command == "ordinal";
+    args.currency |= command == "currency";
+    args.roman |= command == "roman";
+    args.from_roman |= command == "from_roman";
 
-        // Handle thousands
-        if remaining >= 1000 {
-            let thousands = remaining / 1000;
-            remaining %= 1000;
-            if thousands > 1 {
-                result.extend(convert_with_lang(thousands, words)?);
-            }
-            result.push(words.scales[3].0.to_string());
-        }
+    process_input(number, &args)
+}
 
-        // Handle hundreds
-        if remaining >= 100 {
-            let hundreds = remaining / 100;
-            remaining %= 100;
+fn run_file_mode<W: Write>(path: &Path, args: &Args, out: &mut W) -> io::Result<()> {
+    let stderr = io::stderr();
 
-            // Add conjunction for Arabic if needed
-            if !result.is_empty() && !words.and.is_empty() && words.zero == "صفر" {
-                result.push(words.and.to_string());
-            }
+    if path.as_os_str() == "-" {
+        run_batch(io::stdin().lock(), args, out, &mut stderr.lock())
+    } else {
+        let file = File::open(path)?;
+        run_batch(BufReader::new(file), args, out, &mut stderr.lock())
+    }
+}
 
-            // Special handling for Arabic hundreds
-            if words.zero == "صفر" {
-                match hundreds {
-                    1 => result.push("مائة".to_string()),
-                    2 => result.push("مائتان".to_string()),
-                    3..=9 => result.push(format!("{} مائة", words.units[hundreds as usize])),
-                    _ => {}
+/// Converts every line of `reader`, writing results to `out` and reporting
+/// per-line failures (with their line number) to `err` without stopping
+fn run_batch<R: BufRead, W: Write, E: Write>(
+    reader: R,
+    args: &Args,
+    out: &mut W,
+    err: &mut E,
+) -> io::Result<()> {
+    match args.format {
+        // Each line is converted and written as soon as it is read
+        OutputFormat::Text | OutputFormat::Morse => {
+            for (index, line) in reader.lines().enumerate() {
+                let line = line?;
+                let input = line.trim();
+                if input.is_empty() {
+                    continue;
                 }
-            } else if hundreds == 1 {
-                if remaining == 0 {
-                    result.push(words.scales[4].0.to_string());
-                } else {
-                    result.push("Ciento".to_string());
+                match process_line(input, args) {
+                    Ok(text) => writeln!(out, "{}", text)?,
+                    Err(e) => writeln!(err, "Error on line {}: {}", index + 1, e)?,
                 }
-            } else {
-                let hundred_word =
-                    format!("{}cientos", words.units[hundreds as usize].to_lowercase());
-                result.push(
-                    hundred_word
-                        .chars()
-                        .next()
-                        .unwrap()
-                        .to_uppercase()
-                        .collect::<String>()
-                        + &hundred_word[1..],
-                );
             }
         }
-
-        // Handle tens and units
-        if remaining > 0 {
-            if !result.is_empty() && !words.and.is_empty() {
-                result.push(words.and.to_string());
-            }
-
-            if remaining < 20 {
-                result.push(words.units[remaining as usize].to_string());
-            } else {
-                let tens_digit = remaining / 10;
-                let units_digit = remaining % 10;
-
-                // For Arabic, units come before tens
-                if words.zero == "صفر" {
-                    if units_digit > 0 {
-                        result.push(words.units[units_digit as usize].to_string());
-                        if !words.and.is_empty() {
-                            result.push(words.and.to_string());
-                        }
-                    }
-                    result.push(words.tens[tens_digit as usize].to_string());
-                } else {
-                    result.push(words.tens[tens_digit as usize].to_string());
-                    if units_digit > 0 {
-                        if !words.and.is_empty() {
-                            result.push(words.and.to_string());
-                        }
-                        result.push(words.units[units_digit as usize].to_string());
-                    }
-                }
-            }
+        // The JSON array is only complete once every line has been read
+        OutputFormat::Json => {
+            let lines = reader.lines().collect::<io::Result<Vec<_>>>()?;
+            let records = lines
+                .iter()
+                .map(|line| line.trim())
+                .filter(|input| !input.is_empty())
+                .map(|input| ConversionRecord::new(input, args, process_line(input, args)))
+                .collect::<Vec<_>>();
+            writeln!(out, "{}", to_json(&records))?;
         }
-
-        Ok(result)
     }
-}
 
-use converter::{
-    decimal_to_text, number_to_text, number_to_text_lang, to_currency, to_ordinal, to_roman,
-};
+    Ok(())
+}
 
-fn main() {
-    let args = Args::parse();
+/// Largest number of values `--range` will expand to
+const MAX_RANGE_LEN: u64 = 10_000;
+
+/// Parses an inclusive range written "START-END" or "START..=END"
+///
+/// Either bound may be negative ("-3-3", "-5--1").
+fn parse_range(range: &str) -> Result<RangeInclusive<i64>, NumberConversionError> {
+    let invalid = || {
+        NumberConversionError::InvalidInput(format!(
+            "Invalid range: {} (expected START-END, e.g. 1-5)",
+            range
+        ))
+    };
 
-    if let Some(ref number_str) = args.number {
-        // Direct conversion mode
-        match process_input(number_str, &args) {
-            Ok(text) => println!("{}", text),
-            Err(e) => {
-                eprintln!("Error: {}", e);
-                process::exit(1);
-            }
+    // Skip a leading minus so it is not mistaken for the separator
+    let (start, end) = match range.split_once("..=") {
+        Some(bounds) => bounds,
+        None => {
+            let separator = range
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '-')
+                .map(|(index, _)| index)
+                .ok_or_else(invalid)?;
+            (&range[..separator], &range[separator + 1..])
         }
-    } else if args.interactive {
-        // Interactive mode
-        run_interactive_mode();
-    } else {
-        // No arguments provided, show help
-        println!(
-            "Please provide a number using --number or use --interactive for interactive mode"
-        );
-        println!("Use --help for more information");
+    };
+    let start = start.trim().parse::<i64>().map_err(|_| invalid())?;
+    let end = end.trim().parse::<i64>().map_err(|_| invalid())?;
+
+    if start > end {
+        return Err(NumberConversionError::InvalidInput(format!(
+            "Range start {} is greater than its end {}",
+            start, end
+        )));
+    }
+    if end.abs_diff(start) >= MAX_RANGE_LEN {
+        return Err(NumberConversionError::InvalidInput(format!(
+            "Range {} spans more than {} numbers",
+            range, MAX_RANGE_LEN
+        )));
     }
+
+    Ok(start..=end)
 }
 
-fn process_input(input: &str, args: &Args) -> Result<String, NumberConversionError> {
-    // Try parsing as integer first
-    if let Ok(number) = input.parse::<i64>() {
-        if args.roman {
-            return to_roman(number);
-        }
-        if args.ordinal {
-            return to_ordinal(number);
-        }
-        if args.language != "en" {
-            return number_to_text_lang(number, &args.language);
-        }
-        return number_to_text(number);
-    }
+/// Converts every integer in `range` like `run_inputs`
+fn run_range<W: Write, E: Write>(
+    range: &RangeInclusive<i64>,
+    args: &Args,
+    out: &mut W,
+    err: &mut E,
+) -> io::Result<bool> {
+    let numbers = range.clone().map(|n| n.to_string()).collect::<Vec<_>>();
+    let inputs = numbers.iter().map(String::as_str).collect::<Vec<_>>();
+    run_inputs(&inputs, args, out, err)
+}
 
-    // Try parsing as decimal
-    if let Ok(number) = input.parse::<f64>() {
-        if args.currency {
-            return to_currency(number);
+/// Converts each input with the same flags, writing results to `out` and
+/// per-input failures to `err` without stopping; JSON output is a single array
+///
+/// Returns whether every input converted successfully.
+fn run_inputs<W: Write, E: Write>(
+    inputs: &[&str],
+    args: &Args,
+    out: &mut W,
+    err: &mut E,
+) -> io::Result<bool> {
+    let results = inputs
+        .iter()
+        .map(|&input| (input, process_input(input, args)))
+        .collect::<Vec<_>>();
+    let succeeded = results.iter().all(|(_, result)| result.is_ok());
+
+    match args.format {
+        OutputFormat::Text | OutputFormat::Morse => {
+            for (input, result) in results {
+                match result {
+                    Ok(text) => writeln!(out, "{}", text)?,
+                    Err(e) => writeln!(err, "Error: {}: {}", input, e)?,
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let records = results
+                .into_iter()
+                .map(|(input, result)| ConversionRecord::new(input, args, result))
+                .collect::<Vec<_>>();
+            writeln!(out, "{}", to_json(&records))?;
         }
-        return decimal_to_text(number);
     }
 
-    Err(NumberConversionError::InvalidInput(
-        "Invalid number format. Examples of valid formats:\n\
-         - Integer: 42\n\
-         - Decimal: 42.42\n\
-         - Currency: 42.00\n\
-         - Negative: -42"
-            .to_string(),
-    ))
+    Ok(succeeded)
 }
 
-fn run_interactive_mode() {
-    println!("Number to Text Converter");
-    println!("Commands:");
-    println!("  <number>     - Convert a number to text");
-    println!("  o <number>   - Convert to ordinal form");
-    println!("  c <number>   - Format as currency");
-    println!("  r <number>   - Convert to Roman numerals");
-    println!("  quit         - Exit the program");
-    println!();
-
-    loop {
-        print!("> ");
-        io::stdout().flush().unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .expect("Failed to read line");
+    #[test]
+    fn test_process_input() {
+        let default_args = Args {
+            number: None,
+            numbers: Vec::new(),
+            range: None,
+            verbose: false,
+            explain_scale: false,
+            generate_completion: None,
+            file: None,
+            output: None,
+            append: false,
+            interactive: None,
+            ordinal: false,
+            currency: false,
+            roman: false,
+            roman_case: RomanCase::Upper,
+            dozens: false,
+            bytes: false,
+            binary: false,
+            from_roman: false,
+            allow_leading_zeros: false,
+            negative_word: NegativeWord::Minus,
+            sign_placement: SignPlacement::Prefix,
+            zero_word: None,
+            indefinite_article: false,
+            system: ScaleSystem::Short,
+            indian_labels: IndianLabels::Words,
+            bidi: BidiMode::Plain,
+            dialect: None,
+            language: "en".to_string(),
+            annotate: false,
+            arabic_digits: false,
+            format: OutputFormat::Text,
+        };
 
-        let input = input.trim();
+        assert_eq!(process_input("42", &default_args).unwrap(), "Forty Two");
+        assert_eq!(
+            process_input("42.42", &default_args).unwrap(),
+            "Forty Two point Forty Two"
+        );
+        assert!(process_input("invalid", &default_args).is_err());
+        assert_eq!(
+            process_input("0.333...", &default_args).unwrap(),
+            "Zero point Three Repeating"
+        );
 
-        if input.eq_ignore_ascii_case("quit") {
-            break;
-        }
+        let negative_args = Args {
+            negative_word: NegativeWord::Negative,
+            ..default_args
+        };
+        assert_eq!(
+            process_input("-42", &negative_args).unwrap(),
+            "Negative Forty Two"
+        );
 
-        let (command, number) = if let Some(rest) = input.strip_prefix('o') {
-            ("ordinal", rest.trim())
-        } else if let Some(rest) = input.strip_prefix('c') {
-            ("currency", rest.trim())
-        } else if let Some(rest) = input.strip_prefix('r') {
-            ("roman", rest.trim())
-        } else {
-            ("text", input)
+        let nil_args = Args {
+            zero_word: Some(ZeroWord::Nil),
+            ..negative_args
         };
+        assert_eq!(process_input("0", &nil_args).unwrap(), "Nil");
+    }
 
+    #[test]
+    fn test_leading_zeros() {
         let args = Args {
-            number: Some(number.to_string()),
-            interactive: true,
-            ordinal: command == "ordinal",
-            currency: command == "currency",
-            roman: command == "roman",
             language: "en".to_string(),
+            ..Default::default()
         };
+        assert_eq!(process_input("007", &args).unwrap(), "Zero Zero Seven");
+        assert_eq!(process_input("0", &args).unwrap(), "Zero");
+        assert_eq!(process_input("-007", &args).unwrap(), "Minus Seven");
+        assert_eq!(process_input("007.5", &args).unwrap(), "Seven point Fifty");
+
+        let allowed = Args {
+            allow_leading_zeros: true,
+            ..args.clone()
+        };
+        assert_eq!(process_input("007", &allowed).unwrap(), "Seven");
 
-        match process_input(number, &args) {
-            Ok(text) => println!("{}", text),
-            Err(e) => eprintln!("Error: {}", e),
-        }
+        // Other modes read the value rather than the digits
+        let roman = Args {
+            roman: true,
+            ..args.clone()
+        };
+        assert_eq!(process_input("007", &roman).unwrap(), "VII");
+        let ordinal = Args {
+            ordinal: true,
+            ..args.clone()
+        };
+        assert_eq!(process_input("007", &ordinal).unwrap(), "Seven (7th)");
+        let bytes = Args {
+            bytes: true,
+            ..args
+        };
+        assert_eq!(
+            process_input("0100", &bytes).unwrap(),
+            bytes_to_text(100, false).unwrap()
+        );
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
-    fn test_single_digits() {
-        assert_eq!(number_to_text(0).unwrap(), "Zero");
-        assert_eq!(number_to_text(1).unwrap(), "One");
-        assert_eq!(number_to_text(5).unwrap(), "Five");
-        assert_eq!(number_to_text(9).unwrap(), "Nine");
+    fn test_batch_conversion() {
+        let args = Args {
+            language: "en".to_string(),
+            ..Default::default()
+        };
+        let input = "42\n\ninvalid\n-7\nr 9\n1.5\n";
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+
+        run_batch(input.as_bytes(), &args, &mut out, &mut err).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Forty Two\nMinus Seven\nIX\nOne point Fifty\n"
+        );
+        let err = String::from_utf8(err).unwrap();
+        assert!(err.starts_with("Error on line 3: Invalid input"));
+        assert_eq!(err.matches("Error on line").count(), 1);
     }
 
     #[test]
-    fn test_teens() {
-        assert_eq!(number_to_text(10).unwrap(), "Ten");
-        assert_eq!(number_to_text(11).unwrap(), "Eleven");
-        assert_eq!(number_to_text(15).unwrap(), "Fifteen");
-        assert_eq!(number_to_text(19).unwrap(), "Nineteen");
+    fn test_batch_streams_lines() {
+        let args = Args {
+            language: "en".to_string(),
+            ..Default::default()
+        };
+        // Lines before an unreadable one are already written when reading fails
+        let input: &[u8] = b"42\n7\n\xff\n9\n";
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+
+        assert!(run_batch(input, &args, &mut out, &mut err).is_err());
+        assert_eq!(String::from_utf8(out).unwrap(), "Forty Two\nSeven\n");
     }
 
     #[test]
-    fn test_tens() {
-        assert_eq!(number_to_text(20).unwrap(), "Twenty");
-        assert_eq!(number_to_text(42).unwrap(), "Forty Two");
-        assert_eq!(number_to_text(70).unwrap(), "Seventy");
-        assert_eq!(number_to_text(99).unwrap(), "Ninety Nine");
+    fn test_json_output() {
+        let args = Args {
+            language: "en".to_string(),
+            format: OutputFormat::Json,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format_result("42", &args, process_input("42", &args)).unwrap(),
+            r#"{"input":"42","language":"en","text":"Forty Two"}"#
+        );
+
+        let error = format_result("x", &args, process_input("x", &args)).unwrap();
+        assert!(error.starts_with(r#"{"input":"x","error":"Invalid input: "#));
+
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        run_batch("1\nx\n".as_bytes(), &args, &mut out, &mut err).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.starts_with(r#"[{"input":"1","language":"en","text":"One"},{"input":"x","error":"#)
+        );
+        assert!(err.is_empty());
+    }
+
+    /// The arguments of a plain `number_to_text -i`
+    fn interactive_args() -> Args {
+        parse_args(["number_to_text", "-i"], None).unwrap()
     }
 
     #[test]
-    fn test_hundreds() {
-        assert_eq!(number_to_text(100).unwrap(), "One Hundred");
-        assert_eq!(number_to_text(101).unwrap(), "One Hundred and One");
-        assert_eq!(number_to_text(110).unwrap(), "One Hundred and Ten");
-        assert_eq!(number_to_text(999).unwrap(), "Nine Hundred and Ninety Nine");
+    fn test_interactive_from_roman() {
+        let script = "n IX\nn MCMXC\nn IIII\nr 9\nquit\n";
+        let mut output = Vec::new();
+
+        run_interactive(script.as_bytes(), &mut output, &interactive_args(), false).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let results = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("> "))
+            .collect::<Vec<_>>();
+        assert_eq!(results[0], "Nine");
+        assert_eq!(results[1], "One Thousand Nine Hundred and Ninety");
+        assert!(results[2].starts_with("Error: Invalid input"));
+        assert_eq!(results[3], "IX");
     }
 
     #[test]
-    fn test_large_numbers() {
-        assert_eq!(number_to_text(1000).unwrap(), "One Million");
-        assert_eq!(number_to_text(1_000_000).unwrap(), "One Billion");
+    fn test_interactive_roman_round_trip() {
+        let script = "r 1990\nr MCMXC\nr mcmxc\nr MCMXCM\nr 4000\nquit\n";
+        let mut output = Vec::new();
+
+        run_interactive(script.as_bytes(), &mut output, &interactive_args(), true).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let results = output
+            .lines()
+            .skip_while(|line| !line.starts_with("> "))
+            .filter(|line| !line.starts_with("> "))
+            .collect::<Vec<_>>();
+        assert_eq!(results[0], "MCMXC");
+        assert_eq!(results[1], "1990");
+        assert_eq!(results[2], "1990");
         assert_eq!(
-            number_to_text(1_234_567).unwrap(),
-            "One Billion Two Hundred and Thirty Four Million Five Hundred and Sixty Seven"
+            results[3],
+            "Error: Invalid input: Invalid Roman numeral: MCMXCM"
+        );
+        assert_eq!(
+            results[4],
+            "Error: Invalid input: Roman numerals cannot exceed 3999"
         );
     }
 
     #[test]
-    fn test_negative_numbers() {
-        assert_eq!(number_to_text(-1).unwrap(), "Minus One");
-        assert_eq!(number_to_text(-42).unwrap(), "Minus Forty Two");
+    fn test_interactive_script_replay() {
+        let script = "42\no 3\nc 2.5\nr 14\nbogus\nquit\n";
+        let mut output = Vec::new();
+
+        run_interactive(script.as_bytes(), &mut output, &interactive_args(), true).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let session = output
+            .lines()
+            .skip_while(|line| !line.starts_with("> "))
+            .collect::<Vec<_>>();
         assert_eq!(
-            number_to_text(-1234).unwrap(),
-            "Minus One Million Two Hundred and Thirty Four"
+            session[..9],
+            [
+                "> 42",
+                "Forty Two",
+                "> o 3",
+                "Three (3rd)",
+                "> c 2.5",
+                "Two Dollars and Fifty Cents",
+                "> r 14",
+                "XIV",
+                "> bogus",
+            ]
         );
+        assert!(session[9].starts_with("Error: Invalid input"));
+        assert_eq!(session.last(), Some(&"> quit"));
     }
 
+    #[cfg(feature = "lang-es")]
     #[test]
-    fn test_error_handling() {
-        assert!(matches!(
-            number_to_text(i64::MAX),
-            Err(NumberConversionError::ValueTooLarge(_))
-        ));
+    fn test_interactive_switch_language() {
+        let script = "lang\n5\nlang es\nlang\n5\nlang fr\n5\nlang en\n5\nquit\n";
+        let mut output = Vec::new();
+
+        run_interactive(script.as_bytes(), &mut output, &interactive_args(), false).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let results = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("> "))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            [
+                "en",
+                "Five",
+                "Language set to es",
+                "es",
+                "Cinco",
+                "Error: Unsupported language: fr",
+                "Cinco",
+                "Language set to en",
+                "Five",
+            ]
+        );
+    }
 
-        // Test conversion error handling
-        assert!(number_to_text(999_999_999_999_999_999).is_ok());
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn test_interactive_starting_language() {
+        for args in [
+            parse_args(["number_to_text", "-i", "-l", "es"], None).unwrap(),
+            parse_args(["number_to_text", "-i"], Some("es".into())).unwrap(),
+        ] {
+            let mut output = Vec::new();
+            run_interactive("lang\n5\nquit\n".as_bytes(), &mut output, &args, false).unwrap();
+
+            let output = String::from_utf8(output).unwrap();
+            let results = output
+                .lines()
+                .filter_map(|line| line.strip_prefix("> "))
+                .collect::<Vec<_>>();
+            assert_eq!(results[..2], ["es", "Cinco"]);
+        }
     }
 
     #[test]
-    fn test_decimal_numbers() {
-        assert_eq!(decimal_to_text(42.42).unwrap(), "Forty Two point Forty Two");
-        assert_eq!(decimal_to_text(100.05).unwrap(), "One Hundred point Five");
-        assert_eq!(decimal_to_text(-1.50).unwrap(), "Minus One point Fifty");
-        assert_eq!(decimal_to_text(0.99).unwrap(), "Zero point Ninety Nine");
+    fn test_interactive_empty_lines_and_eof() {
+        let script = "\n   \n42\n\n";
+        let mut output = Vec::new();
+
+        run_interactive(script.as_bytes(), &mut output, &interactive_args(), false).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("Error"));
+        assert!(output.ends_with("> > > Forty Two\n> > \n"));
     }
 
     #[test]
-    fn test_process_input() {
-        let default_args = Args {
-            number: None,
-            interactive: false,
-            ordinal: false,
-            currency: false,
-            roman: false,
+    fn test_multiple_inputs() {
+        let mut args = Args {
             language: "en".to_string(),
+            ..Default::default()
         };
+        let inputs = ["42", "x", "-7"];
+
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        assert!(!run_inputs(&inputs, &args, &mut out, &mut err).unwrap());
+        assert_eq!(String::from_utf8(out).unwrap(), "Forty Two\nMinus Seven\n");
+        assert!(String::from_utf8(err)
+            .unwrap()
+            .starts_with("Error: x: Invalid input"));
+
+        args.format = OutputFormat::Json;
+        let mut out = Vec::new();
+        run_inputs(&inputs, &args, &mut out, &mut io::sink()).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with(r#"[{"input":"42","language":"en","text":"Forty Two"},"#));
+        assert!(out
+            .trim_end()
+            .ends_with(r#"{"input":"-7","language":"en","text":"Minus Seven"}]"#));
+    }
 
-        assert_eq!(process_input("42", &default_args).unwrap(), "Forty Two");
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("1-5").unwrap(), 1..=5);
+        assert_eq!(parse_range("1..=10").unwrap(), 1..=10);
+        assert_eq!(parse_range("-5--1").unwrap(), -5..=-1);
+        assert!(matches!(
+            parse_range("5-1"),
+            Err(NumberConversionError::InvalidInput(_))
+        ));
+        assert!(parse_range("0-10000").is_err());
+        assert!(parse_range("1-").is_err());
+        assert!(parse_range("12").is_err());
+
+        let args = Args {
+            language: "en".to_string(),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        assert!(run_range(&(1..=5), &args, &mut out, &mut io::sink()).unwrap());
         assert_eq!(
-            process_input("42.42", &default_args).unwrap(),
-            "Forty Two point Forty Two"
+            String::from_utf8(out).unwrap(),
+            "One\nTwo\nThree\nFour\nFive\n"
         );
-        assert!(process_input("invalid", &default_args).is_err());
     }
 
     #[test]
-    fn test_ordinal_numbers() {
-        assert_eq!(to_ordinal(1).unwrap(), "One (1st)");
-        assert_eq!(to_ordinal(2).unwrap(), "Two (2nd)");
-        assert_eq!(to_ordinal(3).unwrap(), "Three (3rd)");
-        assert_eq!(to_ordinal(4).unwrap(), "Four (4th)");
-        assert_eq!(to_ordinal(11).unwrap(), "Eleven (11th)");
-        assert_eq!(to_ordinal(21).unwrap(), "Twenty One (21st)");
+    fn test_generate_completion() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let mut script = Vec::new();
+            print_completion(shell, &mut script);
+            let script = String::from_utf8(script).unwrap();
+            assert!(script.contains("number_to_text"), "{:?}", shell);
+            assert!(script.contains("language"), "{:?}", shell);
+        }
     }
 
     #[test]
-    fn test_currency() {
-        assert_eq!(to_currency(1.0).unwrap(), "One Dollar");
-        assert_eq!(to_currency(1.01).unwrap(), "One Dollar and One Cent");
+    fn test_print_scale_table() {
+        let mut out = Vec::new();
+        print_scale_table(ScaleSystem::Short, &mut out).unwrap();
+        let table = String::from_utf8(out).unwrap();
+        assert!(table.starts_with("Short scale:\n"));
+        assert!(table.lines().any(|line| line.split_whitespace().eq([
+            "Million",
+            "10^6",
+            "1,000,000"
+        ])));
+
+        let mut out = Vec::new();
+        print_scale_table(ScaleSystem::Indian, &mut out).unwrap();
+        let table = String::from_utf8(out).unwrap();
+        assert!(table.starts_with("Indian scale:\n"));
+        assert!(table.contains("Lakh"));
+    }
+
+    #[test]
+    fn test_describe_groups() {
         assert_eq!(
-            to_currency(2.45).unwrap(),
-            "Two Dollars and Forty Five Cents"
+            describe_groups(1_234_567, ScaleSystem::Short),
+            "groups: [1, 234, 567] scales: [Million, Thousand, -]"
         );
-        assert_eq!(to_currency(100.00).unwrap(), "One Hundred Dollars");
         assert_eq!(
-            to_currency(1.234).unwrap(),
-            "One Dollar and Twenty Three Cents"
+            describe_groups(-42, ScaleSystem::Short),
+            "groups: [42] scales: [-]"
         );
         assert_eq!(
-            to_currency(-1.50).unwrap(),
-            "Minus One Dollar and Fifty Cents"
+            describe_groups(12_345_678, ScaleSystem::Indian),
+            "groups: [1, 23, 45, 678] scales: [Crore, Lakh, Thousand, -]"
+        );
+        assert_eq!(
+            describe_groups(1_000_000_000, ScaleSystem::Long),
+            "groups: [1, 0, 0, 0] scales: [Milliard, Million, Thousand, -]"
+        );
+    }
+
+    #[test]
+    fn test_language_from_env() {
+        // An explicit flag always wins over the environment
+        let args = parse_args(["number_to_text", "-n", "5", "-l", "en"], Some("xx".into()));
+        assert_eq!(args.unwrap().language, "en");
+
+        let args = parse_args(["number_to_text", "-n", "5"], Some("fr".into())).unwrap();
+        assert_eq!(args.language, "en");
+        let args = parse_args(["number_to_text", "-n", "5"], None).unwrap();
+        assert_eq!(args.language, "en");
+
+        let mut err = Vec::new();
+        assert_eq!(language_from_env(Some("xx".to_string()), &mut err), "en");
+        assert_eq!(
+            String::from_utf8(err).unwrap(),
+            "Warning: Unsupported language: xx in NUM2TEXT_LANG, using en\n"
+        );
+        let mut err = Vec::new();
+        assert_eq!(language_from_env(Some(" EN ".to_string()), &mut err), "en");
+        assert_eq!(language_from_env(None, &mut err), "en");
+        assert!(err.is_empty());
+    }
+
+    #[cfg(all(feature = "lang-es", feature = "lang-ar"))]
+    #[test]
+    fn test_language_from_env_supported() {
+        let args = parse_args(["number_to_text", "-n", "5"], Some("es".into())).unwrap();
+        assert_eq!(args.language, "es");
+        assert_eq!(process_input("5", &args).unwrap(), "Cinco");
+
+        let mut err = Vec::new();
+        assert_eq!(language_from_env(Some(" AR ".to_string()), &mut err), "ar");
+        assert!(err.is_empty());
+    }
+
+    #[test]
+    fn test_output_file() {
+        let path = env::temp_dir().join(format!("number_to_text_{}.txt", process::id()));
+        let mut args = Args {
+            language: "en".to_string(),
+            output: Some(path.clone()),
+            ..Default::default()
+        };
+
+        for inputs in [["1", "2"], ["3", "4"]] {
+            let mut out = open_output(&args).unwrap();
+            let mut err = Vec::new();
+            assert!(run_inputs(&inputs, &args, &mut out, &mut err).unwrap());
+            out.flush().unwrap();
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Three\nFour\n");
+
+        args.append = true;
+        let mut out = open_output(&args).unwrap();
+        let mut err = Vec::new();
+        assert!(!run_inputs(&["5", "x"], &args, &mut out, &mut err).unwrap());
+        drop(out);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "Three\nFour\nFive\n"
         );
+        assert!(String::from_utf8(err).unwrap().starts_with("Error"));
+
+        std::fs::remove_file(&path).unwrap();
+        args.output = Some(path.join("missing"));
+        assert!(open_output(&args).is_err());
     }
 
     #[test]
-    fn test_roman_numerals() {
-        assert_eq!(to_roman(1).unwrap(), "I");
-        assert_eq!(to_roman(4).unwrap(), "IV");
-        assert_eq!(to_roman(9).unwrap(), "IX");
-        assert_eq!(to_roman(49).unwrap(), "XLIX");
-        assert_eq!(to_roman(99).unwrap(), "XCIX");
-        assert_eq!(to_roman(499).unwrap(), "CDXCIX");
-        assert_eq!(to_roman(999).unwrap(), "CMXCIX");
-        assert_eq!(to_roman(3999).unwrap(), "MMMCMXCIX");
-        assert!(to_roman(0).is_err());
-        assert!(to_roman(-1).is_err());
-        assert!(to_roman(4000).is_err());
+    fn test_morse_format() {
+        let args = Args {
+            language: "en".to_string(),
+            format: OutputFormat::Morse,
+            ..Default::default()
+        };
+        assert_eq!(process_input("123", &args).unwrap(), ".---- ..--- ...--");
+        assert_eq!(process_input("-7", &args).unwrap(), "-....- --...");
+        assert_eq!(
+            process_input("1,024", &args).unwrap(),
+            ".---- / ----- ..--- ....-"
+        );
+        assert!(process_input("1.5", &args).is_err());
     }
 
     #[test]
-    fn test_spanish_numbers() {
-        assert_eq!(number_to_text_lang(0, "es").unwrap(), "Cero");
-        assert_eq!(number_to_text_lang(1, "es").unwrap(), "Uno");
-        assert_eq!(number_to_text_lang(21, "es").unwrap(), "Veinte y Uno");
+    fn test_currency_sign_suffix() {
+        let args = Args {
+            language: "en".to_string(),
+            currency: true,
+            sign_placement: SignPlacement::Suffix,
+            ..Default::default()
+        };
         assert_eq!(
-            number_to_text_lang(1234, "es").unwrap(),
-            "Mil Doscientos y Treinta y Cuatro"
+            process_input("-100.00", &args).unwrap(),
+            "One Hundred Dollars Minus"
         );
-        assert!(number_to_text_lang(42, "fr").is_err());
     }
 
     #[test]
-    fn test_arabic_numbers() {
-        assert_eq!(number_to_text_lang(0, "ar").unwrap(), "صفر");
-        assert_eq!(number_to_text_lang(1, "ar").unwrap(), "واحد");
-        assert_eq!(number_to_text_lang(11, "ar").unwrap(), "أحد عشر");
-        assert_eq!(number_to_text_lang(21, "ar").unwrap(), "واحد و عشرون");
+    fn test_annotate_grouping() {
+        let us_args = Args {
+            annotate: true,
+            language: "en".to_string(),
+            ..Default::default()
+        };
         assert_eq!(
-            number_to_text_lang(1234, "ar").unwrap(),
-            "ألف و مائتان و أربعة و ثلاثون"
+            process_input("1234567", &us_args).unwrap(),
+            "One Million Two Hundred and Thirty Four Thousand Five Hundred and Sixty Seven \
+             (1,234,567)"
         );
-        assert!(number_to_text_lang(42, "fr").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lang-es")]
+    fn test_annotate_grouping_european() {
+        let european_args = Args {
+            annotate: true,
+            language: "es".to_string(),
+            ..Default::default()
+        };
+        assert!(process_input("1234567", &european_args)
+            .unwrap()
+            .ends_with(" (1.234.567)"));
+        assert!(process_input("-1234567", &european_args)
+            .unwrap()
+            .ends_with(" (-1.234.567)"));
+    }
+
+    #[test]
+    #[cfg(feature = "lang-ar")]
+    fn test_annotate_arabic_digits() {
+        let arabic_args = Args {
+            annotate: true,
+            arabic_digits: true,
+            language: "ar".to_string(),
+            ..Default::default()
+        };
+        assert!(process_input("1234567", &arabic_args)
+            .unwrap()
+            .ends_with(" (١٬٢٣٤٬٥٦٧)"));
     }
 }
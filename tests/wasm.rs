@@ -0,0 +1,21 @@
+//! Round trip through the JavaScript bindings; run with
+//! `wasm-pack test --node -- --features wasm`
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use number_to_text::wasm;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_wasm_round_trip() {
+    assert_eq!(wasm::number_to_text(42, "en").unwrap(), "Forty Two");
+    assert_eq!(wasm::number_to_text(1, "es").unwrap(), "Uno");
+    assert_eq!(wasm::to_roman(1994).unwrap(), "MCMXCIV");
+    assert_eq!(wasm::to_ordinal(3).unwrap(), "Three (3rd)");
+    assert_eq!(
+        wasm::to_currency(2.45).unwrap(),
+        "Two Dollars and Forty Five Cents"
+    );
+
+    let error = wasm::number_to_text(42, "fr").unwrap_err();
+    assert_eq!(error.as_string().unwrap(), "Unsupported language: fr");
+}